@@ -0,0 +1,53 @@
+//! Benchmark of the search for the month viewed by a newly created picker,
+//! run it with `cargo bench --features test-utils`.
+use chrono::NaiveDate;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use seed_datepicker::{
+    config::{
+        date_constraints::{DateConstraints, DateConstraintsBuilder},
+        PickerConfigBuilder,
+    },
+    options::PickerOptionsBuilder,
+    test_utils::ModelBuilder,
+};
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("Invalid date")
+}
+
+fn viewed_date(constraints: &DateConstraints) -> NaiveDate {
+    let config = PickerConfigBuilder::default()
+        .date_constraints(constraints.clone())
+        .build()
+        .unwrap();
+    let options = PickerOptionsBuilder::default()
+        .today_provider(|| ymd(2024, 3, 14))
+        .build()
+        .unwrap();
+    *ModelBuilder::new(config, constraints.clone())
+        .options(options)
+        .build()
+        .viewed_date()
+}
+
+fn initial_viewed_date(c: &mut Criterion) {
+    let far_min_date = DateConstraintsBuilder::default()
+        .min_date(ymd(2110, 1, 1))
+        .build()
+        .unwrap();
+    let nothing_allowed = DateConstraintsBuilder::default()
+        .min_date(ymd(2024, 1, 1))
+        .max_date(ymd(2024, 1, 1))
+        .disabled_unique_dates(std::iter::once(ymd(2024, 1, 1)).collect())
+        .build()
+        .unwrap();
+    c.bench_function("min_date a century later", |b| {
+        b.iter(|| viewed_date(black_box(&far_min_date)))
+    });
+    c.bench_function("no allowed month", |b| {
+        b.iter(|| viewed_date(black_box(&nothing_allowed)))
+    });
+}
+
+criterion_group!(benches, initial_viewed_date);
+criterion_main!(benches);
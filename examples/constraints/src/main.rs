@@ -12,37 +12,42 @@ pub struct Model {
 }
 
 fn init(url: Url, orders: &mut impl Orders<Msg>) -> Model {
+    let date_constraints = DateConstraintsBuilder::default()
+        // earliest selectable date
+        .min_date(NaiveDate::from_ymd(2020, 12, 1))
+        // latest selectable date
+        .max_date(NaiveDate::from_ymd(2022, 12, 14))
+        // chrono Weekday-s that can be disabled
+        .disabled_weekdays([Weekday::Sat, Weekday::Sun].iter().cloned().collect())
+        // entire chrono Month-s that can be disabled
+        .disabled_months([Month::July, Month::August].iter().cloned().collect())
+        // entire years that can be disabled
+        .disabled_years([2021].iter().cloned().collect())
+        // a particular day of month that is disabled in all months
+        .disabled_monthly_dates([13].iter().cloned().collect())
+        // particular dates that are disabled each year (the year number is ignored here)
+        .disabled_yearly_dates(vec![
+            NaiveDate::from_ymd(1, 12, 24),
+            NaiveDate::from_ymd(1, 12, 25),
+            NaiveDate::from_ymd(1, 12, 26),
+        ])
+        // particular unique dates that can be disabled
+        .disabled_unique_dates([NaiveDate::from_ymd(2020, 12, 8)].iter().cloned().collect())
+        .build()
+        .unwrap();
     let config = PickerConfigBuilder::default()
         .initial_date(NaiveDate::from_ymd(2020, 12, 15))
-        .date_constraints(
-            DateConstraintsBuilder::default()
-                // earliest selectable date
-                .min_date(NaiveDate::from_ymd(2020, 12, 1))
-                // latest selectable date
-                .max_date(NaiveDate::from_ymd(2022, 12, 14))
-                // chrono Weekday-s that can be disabled
-                .disabled_weekdays([Weekday::Sat, Weekday::Sun].iter().cloned().collect())
-                // entire chrono Month-s that can be disabled
-                .disabled_months([Month::July, Month::August].iter().cloned().collect())
-                // entire years that can be disabled
-                .disabled_years([2021].iter().cloned().collect())
-                // a particular day of month that is disabled in all months
-                .disabled_monthly_dates([13].iter().cloned().collect())
-                // particular dates that are disabled each year (the year number is ignored here)
-                .disabled_yearly_dates(vec![
-                    NaiveDate::from_ymd(1, 12, 24),
-                    NaiveDate::from_ymd(1, 12, 25),
-                    NaiveDate::from_ymd(1, 12, 26),
-                ])
-                // particular unique dates that can be disabled
-                .disabled_unique_dates([NaiveDate::from_ymd(2020, 12, 8)].iter().cloned().collect())
-                .build()
-                .unwrap(),
-        )
+        .date_constraints(date_constraints.clone())
         .build()
         .unwrap();
     Model {
-        date_picker: seed_datepicker::init(url, orders, config, Msg::DatePickerMsg),
+        date_picker: seed_datepicker::init(
+            url,
+            orders,
+            config,
+            date_constraints,
+            Msg::DatePickerMsg,
+        ),
     }
 }
 
@@ -60,7 +65,7 @@ pub fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
                 picker_msg,
                 &mut model.date_picker,
                 orders,
-                Msg::DateSelected,
+                |_| Msg::DateSelected,
                 Msg::DatePickerMsg,
             );
         }
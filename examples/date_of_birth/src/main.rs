@@ -0,0 +1,96 @@
+use chrono::prelude::*;
+use seed::{prelude::*, *};
+use seed_datepicker::config::date_constraints::DateConstraints;
+use seed_datepicker::config::{dob_constraints_until, DateOfBirthPreset, PickerConfigBuilder};
+
+type DatePickerModel = seed_datepicker::Model<DateConstraints>;
+
+/// `Model` describes our app state.
+pub struct Model {
+    date_picker: DatePickerModel,
+    /// age computed from the selected date of birth, kept in sync with the picker
+    age: Option<i32>,
+}
+
+fn init(url: Url, orders: &mut impl Orders<Msg>) -> Model {
+    let today = Local::now().naive_local().date();
+    let config = PickerConfigBuilder::default()
+        .dob_mode_until(today)
+        .build()
+        .unwrap();
+    Model {
+        date_picker: seed_datepicker::init(
+            url,
+            orders,
+            config,
+            dob_constraints_until(today),
+            Msg::DatePickerMsg,
+        ),
+        age: None,
+    }
+}
+
+// `Msg` describes the different events you can modify state with.
+pub enum Msg {
+    DatePickerMsg(seed_datepicker::Msg),
+    DateSelected,
+}
+
+// `update` describes how to handle each `Msg`.
+pub fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
+    match msg {
+        Msg::DatePickerMsg(picker_msg) => {
+            seed_datepicker::update(
+                picker_msg,
+                &mut model.date_picker,
+                orders,
+                |_| Msg::DateSelected,
+                Msg::DatePickerMsg,
+            );
+        }
+        // sync the second input with the newly selected date
+        Msg::DateSelected => {
+            model.age = model.date_picker.selected_date().map(age);
+        }
+    };
+}
+
+/// number of full years since the `date_of_birth`
+fn age(date_of_birth: NaiveDate) -> i32 {
+    let today = Local::now().naive_local().date();
+    let had_birthday = (today.month(), today.day()) >= (date_of_birth.month(), date_of_birth.day());
+    today.year() - date_of_birth.year() - if had_birthday { 0 } else { 1 }
+}
+
+// `view` describes what to display.
+pub fn view(model: &Model) -> Node<Msg> {
+    div![
+        input![
+            C!["textbox"],
+            attrs! {
+                At::Value => model.date_picker.selected_date().map_or("".into(), |optval|optval.format("%e %b %Y").to_string()),
+                At::Type => "text",
+                At::ReadOnly => "",
+                At::Placeholder => "Date of birth",
+            },
+            ev(Ev::Click, |_| Msg::DatePickerMsg(
+                seed_datepicker::Msg::OpenDialog(None)
+            )),
+        ],
+        input![
+            C!["textbox"],
+            attrs! {
+                At::Value => model.age.map_or("".into(), |age| age.to_string()),
+                At::Type => "text",
+                At::ReadOnly => "",
+                At::Placeholder => "Age",
+            },
+        ],
+        seed_datepicker::view(&model.date_picker, Msg::DatePickerMsg),
+    ]
+}
+
+pub fn main() {
+    // Mount the `app` to the element with the `id` "app".
+    App::start("app", init, update, view);
+}
@@ -0,0 +1,93 @@
+use seed::{prelude::*, *};
+use seed_datepicker::config::date_constraints::DateConstraints;
+use seed_datepicker::config::PickerConfigBuilder;
+use seed_datepicker::linked::{self, LinkedMsg, LinkedPair};
+
+/// `Model` describes our app state.
+pub struct Model {
+    trip: LinkedPair,
+}
+
+fn init(url: Url, orders: &mut impl Orders<Msg>) -> Model {
+    Model {
+        trip: linked::init(
+            url,
+            orders,
+            PickerConfigBuilder::default().build().unwrap(),
+            DateConstraints::default(),
+            PickerConfigBuilder::default().build().unwrap(),
+            DateConstraints::default(),
+            Msg::TripMsg,
+        ),
+    }
+}
+
+// `Msg` describes the different events you can modify state with.
+pub enum Msg {
+    TripMsg(LinkedMsg),
+    DepartureSelected,
+    ReturnSelected,
+}
+
+// `update` describes how to handle each `Msg`.
+pub fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
+    match msg {
+        Msg::TripMsg(trip_msg) => {
+            linked::update(
+                trip_msg,
+                &mut model.trip,
+                orders,
+                Msg::DepartureSelected,
+                Msg::ReturnSelected,
+                Msg::TripMsg,
+            );
+        }
+        // do anything with the newly selected dates
+        Msg::DepartureSelected => {
+            log!("Departure: ", model.trip.from().selected_date());
+        }
+        Msg::ReturnSelected => {
+            log!("Return: ", model.trip.to().selected_date());
+        }
+    };
+}
+
+fn view_textbox(
+    selected_date: &Option<chrono::NaiveDate>,
+    placeholder: &str,
+    open_msg: LinkedMsg,
+) -> Node<Msg> {
+    input![
+        C!["textbox"],
+        attrs! {
+            At::Value => selected_date.map_or("".into(), |optval|optval.format("%e %b %Y").to_string()),
+            At::Type => "text",
+            At::ReadOnly => "",
+            At::Placeholder => placeholder,
+        },
+        ev(Ev::Click, |_| Msg::TripMsg(open_msg)),
+    ]
+}
+
+// `view` describes what to display.
+pub fn view(model: &Model) -> Node<Msg> {
+    div![
+        view_textbox(
+            model.trip.from().selected_date(),
+            "Departure",
+            LinkedMsg::From(seed_datepicker::Msg::OpenDialog(None)),
+        ),
+        view_textbox(
+            model.trip.to().selected_date(),
+            "Return",
+            LinkedMsg::To(seed_datepicker::Msg::OpenDialog(None)),
+        ),
+        linked::view_from(&model.trip, Msg::TripMsg),
+        linked::view_to(&model.trip, Msg::TripMsg),
+    ]
+}
+
+pub fn main() {
+    // Mount the `app` to the element with the `id` "app".
+    App::start("app", init, update, view);
+}
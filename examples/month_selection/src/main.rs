@@ -2,6 +2,7 @@ use seed::{prelude::*, *};
 use seed_datepicker::config::date_constraints::DateConstraints;
 use seed_datepicker::config::PickerConfigBuilder;
 use seed_datepicker::dialog_view_type::DialogViewType;
+use seed_datepicker::format::format_for_display;
 
 type DatePickerModel = seed_datepicker::Model<DateConstraints>;
 
@@ -17,7 +18,13 @@ fn init(url: Url, orders: &mut impl Orders<Msg>) -> Model {
         .build()
         .unwrap();
     Model {
-        date_picker: seed_datepicker::init(url, orders, config, Msg::DatePickerMsg),
+        date_picker: seed_datepicker::init(
+            url,
+            orders,
+            config,
+            DateConstraints::default(),
+            Msg::DatePickerMsg,
+        ),
     }
 }
 
@@ -35,7 +42,7 @@ pub fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
                 picker_msg,
                 &mut model.date_picker,
                 orders,
-                Msg::DateSelected,
+                |_| Msg::DateSelected,
                 Msg::DatePickerMsg,
             );
         }
@@ -53,7 +60,7 @@ pub fn view(model: &Model) -> Node<Msg> {
         input![
             C!["textbox"],
             attrs! {
-                At::Value => model.date_picker.selected_date().map_or("".into(), |optval|optval.format("%b %Y").to_string()),
+                At::Value => model.date_picker.selected_date().map_or("".into(), |optval|format_for_display(&optval, model.date_picker.config(), model.date_picker.options())),
                 At::Type => "text",
                 At::ReadOnly => "",
                 At::Placeholder => "Click HERE",
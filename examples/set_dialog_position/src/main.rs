@@ -12,7 +12,13 @@ pub struct Model {
 fn init(url: Url, orders: &mut impl Orders<Msg>) -> Model {
     let config = PickerConfigBuilder::default().build().unwrap();
     Model {
-        date_picker: seed_datepicker::init(url, orders, config, Msg::DatePickerMsg),
+        date_picker: seed_datepicker::init(
+            url,
+            orders,
+            config,
+            DateConstraints::default(),
+            Msg::DatePickerMsg,
+        ),
     }
 }
 
@@ -30,7 +36,7 @@ pub fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
                 picker_msg,
                 &mut model.date_picker,
                 orders,
-                Msg::DateSelected,
+                |_| Msg::DateSelected,
                 Msg::DatePickerMsg,
             );
         }
@@ -1,3 +1,4 @@
+use chrono::NaiveDate;
 use seed::{prelude::*, *};
 use seed_datepicker::config::date_constraints::DateConstraints;
 use seed_datepicker::config::PickerConfigBuilder;
@@ -12,14 +13,20 @@ pub struct Model {
 fn init(url: Url, orders: &mut impl Orders<Msg>) -> Model {
     let config = PickerConfigBuilder::default().build().unwrap();
     Model {
-        date_picker: seed_datepicker::init(url, orders, config, Msg::DatePickerMsg),
+        date_picker: seed_datepicker::init(
+            url,
+            orders,
+            config,
+            DateConstraints::default(),
+            Msg::DatePickerMsg,
+        ),
     }
 }
 
 // `Msg` describes the different events you can modify state with.
 pub enum Msg {
     DatePickerMsg(seed_datepicker::Msg),
-    DateSelected,
+    DateSelected(Option<NaiveDate>),
 }
 
 // `update` describes how to handle each `Msg`.
@@ -35,8 +42,8 @@ pub fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
             );
         }
         // do anything with the newly selected date
-        Msg::DateSelected => {
-            log!("New date was selected: ", model.date_picker.selected_date());
+        Msg::DateSelected(selected_date) => {
+            log!("New date was selected: ", selected_date);
         }
     };
 }
@@ -0,0 +1,111 @@
+//! Calendar systems of the day and month views, the `NaiveDate`s of the picker and the constraints
+//! stay Gregorian, only the grouping of the days into months and years and their labels change.
+use chrono::prelude::*;
+use num_traits::FromPrimitive;
+
+use crate::date::saturated_date;
+
+/// date in a particular `CalendarSystem`, the months and days are numbered from 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CalendarDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+/// `CalendarSystem` groups the days into months and years, e.g. for Hijri or Persian calendar pickers.
+pub trait CalendarSystem {
+    fn calendar_date(&self, date: &NaiveDate) -> CalendarDate;
+
+    /// `None` if the `date` doesn't exist in the calendar or is out of the range of `NaiveDate`
+    fn to_naive_date(&self, date: &CalendarDate) -> Option<NaiveDate>;
+
+    /// number of months in the `year`
+    fn month_count(&self, year: i32) -> u32;
+
+    fn days_in_month(&self, year: i32, month: u32) -> u32;
+
+    fn month_name(&self, year: i32, month: u32) -> String;
+
+    /// first day of the month that contains the `date`, `NaiveDate::MIN` if that day is earlier
+    fn first_day_of_month(&self, date: &NaiveDate) -> NaiveDate {
+        let date = self.calendar_date(date);
+        self.to_naive_date(&CalendarDate { day: 1, ..date })
+            .unwrap_or(NaiveDate::MIN)
+    }
+
+    /// first day of the month that is `months` months away from the month of the `date`,
+    /// saturating at `NaiveDate::MIN` and `NaiveDate::MAX`
+    fn month_offset(&self, date: &NaiveDate, months: i32) -> NaiveDate {
+        let date = self.calendar_date(date);
+        let mut year = date.year;
+        let mut month = date.month as i32 + months;
+        while month < 1 {
+            year -= 1;
+            month += self.month_count(year) as i32;
+        }
+        while month > self.month_count(year) as i32 {
+            month -= self.month_count(year) as i32;
+            year += 1;
+        }
+        self.to_naive_date(&CalendarDate {
+            year,
+            month: month as u32,
+            day: 1,
+        })
+        .unwrap_or(saturated_date(months))
+    }
+
+    /// first day of the year that is `years` years away from the year of the `date`,
+    /// saturating at `NaiveDate::MIN` and `NaiveDate::MAX`
+    fn year_offset(&self, date: &NaiveDate, years: i32) -> NaiveDate {
+        self.to_naive_date(&CalendarDate {
+            year: self.calendar_date(date).year.saturating_add(years),
+            month: 1,
+            day: 1,
+        })
+        .unwrap_or(saturated_date(years))
+    }
+
+    /// the `day` of the month that contains the `month_date`, clamped to the length of the month,
+    /// the `month_date` itself if that day is out of the range of `NaiveDate`
+    fn with_day_clamped(&self, month_date: &NaiveDate, day: u32) -> NaiveDate {
+        let date = self.calendar_date(month_date);
+        let day = day.min(self.days_in_month(date.year, date.month)).max(1);
+        self.to_naive_date(&CalendarDate { day, ..date })
+            .unwrap_or(*month_date)
+    }
+}
+
+/// the Gregorian calendar used by default
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GregorianCalendar;
+
+impl CalendarSystem for GregorianCalendar {
+    fn calendar_date(&self, date: &NaiveDate) -> CalendarDate {
+        CalendarDate {
+            year: date.year(),
+            month: date.month(),
+            day: date.day(),
+        }
+    }
+
+    fn to_naive_date(&self, date: &CalendarDate) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(date.year, date.month, date.day)
+    }
+
+    fn month_count(&self, _year: i32) -> u32 {
+        12
+    }
+
+    fn days_in_month(&self, year: i32, month: u32) -> u32 {
+        (28..=31)
+            .rev()
+            .find(|day| NaiveDate::from_ymd_opt(year, month, *day).is_some())
+            .unwrap_or(0)
+    }
+
+    fn month_name(&self, _year: i32, month: u32) -> String {
+        Month::from_u32(month).map_or_else(|| month.to_string(), |month| month.name().to_string())
+    }
+}
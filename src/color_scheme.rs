@@ -0,0 +1,42 @@
+use seed::{prelude::*, *};
+
+const PREFERS_DARK_MEDIA_QUERY: &str = "(prefers-color-scheme: dark)";
+
+/// `ColorSchemeListener` listens to the changes of the `prefers-color-scheme` media query,
+/// the listener is removed when it gets dropped.
+pub(crate) struct ColorSchemeListener {
+    media_query_list: web_sys::MediaQueryList,
+    callback: Closure<dyn Fn()>,
+}
+
+impl ColorSchemeListener {
+    /// starts listening, `on_change` is called on every change of the preferred color scheme,
+    /// returns `None` if the browser does not support the media query
+    pub(crate) fn new(on_change: impl Fn() + 'static) -> Option<Self> {
+        let media_query_list = window()
+            .match_media(PREFERS_DARK_MEDIA_QUERY)
+            .ok()
+            .flatten()?;
+        let callback = Closure::wrap(Box::new(on_change) as Box<dyn Fn()>);
+        media_query_list
+            .add_event_listener_with_callback("change", callback.as_ref().unchecked_ref())
+            .ok()?;
+        Some(Self {
+            media_query_list,
+            callback,
+        })
+    }
+
+    /// whether the dark color scheme is currently preferred
+    pub(crate) fn prefers_dark(&self) -> bool {
+        self.media_query_list.matches()
+    }
+}
+
+impl Drop for ColorSchemeListener {
+    fn drop(&mut self) {
+        self.media_query_list
+            .remove_event_listener_with_callback("change", self.callback.as_ref().unchecked_ref())
+            .ok();
+    }
+}
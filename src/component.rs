@@ -0,0 +1,74 @@
+//! `DatePicker` component for the apps that map the messages of the picker with `orders.proxy`
+//! and `Node::map_msg` instead of passing the `to_msg` functions to `init`, `update` and `view`.
+use std::convert::identity;
+
+use chrono::NaiveDate;
+use chrono_datepicker_core::config::{date_constraints::HasDateConstraints, PickerConfig};
+use seed::prelude::*;
+
+use crate::{config::date_constraints::DateConstraints, options::PickerOptions, Model, Msg};
+
+/// `DatePicker` wraps the `Model` together with the `update` and `view` functions:
+///
+/// ```rust,ignore
+/// Msg::DatePicker(msg) => {
+///     if let Some(date) = model.date_picker.update(msg, &mut orders.proxy(Msg::DatePicker)) {
+///         log!("New date was selected: ", date);
+///     }
+/// }
+/// // in the view
+/// model.date_picker.view().map_msg(Msg::DatePicker)
+/// ```
+#[derive(Debug)]
+pub struct DatePicker<T: HasDateConstraints + Default + Clone = DateConstraints> {
+    model: Model<T>,
+}
+
+impl<T: HasDateConstraints + Default + Clone> DatePicker<T> {
+    /// picker with the default `PickerOptions`, the `date_constraints` are the ones the `config` was built with
+    pub fn new(config: PickerConfig<T>, date_constraints: T) -> Self {
+        Self {
+            model: Model::new(config, date_constraints, PickerOptions::default(), None),
+        }
+    }
+
+    /// picker with the given `PickerOptions`, the `orders` are needed for the `ColorScheme::Auto`
+    pub fn with_options(
+        config: PickerConfig<T>,
+        date_constraints: T,
+        options: PickerOptions,
+        orders: &mut impl Orders<Msg>,
+    ) -> Self {
+        Self {
+            model: crate::init_with_options(
+                Url::new(),
+                orders,
+                config,
+                date_constraints,
+                options,
+                identity,
+            ),
+        }
+    }
+
+    /// handles the `msg`, returns the new selection if it changed, `Some(None)` if it was cleared
+    pub fn update(&mut self, msg: Msg, orders: &mut impl Orders<Msg>) -> Option<Option<NaiveDate>> {
+        crate::update_model(msg, &mut self.model, orders, identity)
+    }
+
+    pub fn view(&self) -> Node<Msg> {
+        crate::view(&self.model, identity)
+    }
+
+    pub fn model(&self) -> &Model<T> {
+        &self.model
+    }
+
+    pub fn model_mut(&mut self) -> &mut Model<T> {
+        &mut self.model
+    }
+
+    pub fn selected_date(&self) -> &Option<NaiveDate> {
+        self.model.selected_date()
+    }
+}
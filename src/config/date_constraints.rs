@@ -0,0 +1,340 @@
+use std::{collections::HashSet, error::Error, fmt};
+
+use chrono::prelude::*;
+use chrono_datepicker_core::viewed_date::{DayNumber, YearNumber};
+use derive_builder::{Builder, UninitializedFieldError};
+use getset::Getters;
+use num_traits::FromPrimitive;
+
+use crate::{navigation::year_group_range, style_names};
+
+pub use chrono_datepicker_core::config::date_constraints::HasDateConstraints;
+
+pub mod presets;
+
+/// `DateRejection` describes why a date can't be selected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateRejection {
+    /// the date is earlier than the `min_date`
+    BeforeMin(NaiveDate),
+    /// the date is later than the `max_date`
+    AfterMax(NaiveDate),
+    DisabledWeekday(Weekday),
+    DisabledMonth(Month),
+    DisabledYear(YearNumber),
+    /// the day of month is disabled in all months
+    DisabledMonthlyDate(DayNumber),
+    /// the day and month are disabled in all years
+    DisabledYearlyDate,
+    /// the date is disabled as a unique date
+    DisabledUniqueDate,
+    /// the date is forbidden by constraints that do not provide any details
+    Forbidden,
+}
+
+impl fmt::Display for DateRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateRejection::BeforeMin(min_date) => write!(f, "earlier than {}", min_date),
+            DateRejection::AfterMax(max_date) => write!(f, "later than {}", max_date),
+            DateRejection::DisabledWeekday(weekday) => write!(f, "{} is disabled", weekday),
+            DateRejection::DisabledMonth(month) => write!(f, "{} is disabled", month.name()),
+            DateRejection::DisabledYear(year) => write!(f, "{} is disabled", year),
+            DateRejection::DisabledMonthlyDate(day) => {
+                write!(f, "day {} is disabled in every month", day)
+            }
+            DateRejection::DisabledYearlyDate => write!(f, "disabled in every year"),
+            DateRejection::DisabledUniqueDate => write!(f, "disabled"),
+            DateRejection::Forbidden => write!(f, "forbidden"),
+        }
+    }
+}
+
+impl Error for DateRejection {}
+
+impl DateRejection {
+    /// category of the rule that rejected the date, `None` for `DateRejection::Forbidden`
+    pub fn rule(&self) -> Option<ConstraintRule> {
+        match self {
+            DateRejection::BeforeMin(_) | DateRejection::AfterMax(_) => {
+                Some(ConstraintRule::OutOfRange)
+            }
+            DateRejection::DisabledWeekday(_) => Some(ConstraintRule::DisabledWeekdays),
+            DateRejection::DisabledMonth(_) => Some(ConstraintRule::DisabledMonths),
+            DateRejection::DisabledYear(_) => Some(ConstraintRule::DisabledYears),
+            DateRejection::DisabledMonthlyDate(_) => Some(ConstraintRule::DisabledMonthlyDates),
+            DateRejection::DisabledYearlyDate => Some(ConstraintRule::DisabledYearlyDates),
+            DateRejection::DisabledUniqueDate => Some(ConstraintRule::DisabledUniqueDates),
+            DateRejection::Forbidden => None,
+        }
+    }
+}
+
+/// `ConstraintRule` is a category of the rules of the `DateConstraints`,
+/// e.g. for a legend explaining the styles of the unavailable days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConstraintRule {
+    /// before the `min_date` or after the `max_date`
+    OutOfRange,
+    DisabledWeekdays,
+    DisabledMonths,
+    DisabledYears,
+    DisabledMonthlyDates,
+    DisabledYearlyDates,
+    DisabledUniqueDates,
+}
+
+impl ConstraintRule {
+    /// class added to the unavailable day cells and to the legend swatch of the rule
+    pub fn class_name(&self) -> &'static str {
+        match self {
+            ConstraintRule::OutOfRange => style_names::OUT_OF_RANGE,
+            ConstraintRule::DisabledWeekdays => style_names::DISABLED_WEEKDAY,
+            ConstraintRule::DisabledMonths => style_names::DISABLED_MONTH,
+            ConstraintRule::DisabledYears => style_names::DISABLED_YEAR,
+            ConstraintRule::DisabledMonthlyDates => style_names::DISABLED_MONTHLY_DATE,
+            ConstraintRule::DisabledYearlyDates => style_names::DISABLED_YEARLY_DATE,
+            ConstraintRule::DisabledUniqueDates => style_names::DISABLED_UNIQUE_DATE,
+        }
+    }
+}
+
+impl fmt::Display for ConstraintRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ConstraintRule::OutOfRange => "out of range",
+            ConstraintRule::DisabledWeekdays => "disabled weekday",
+            ConstraintRule::DisabledMonths => "disabled month",
+            ConstraintRule::DisabledYears => "disabled year",
+            ConstraintRule::DisabledMonthlyDates => "disabled day of month",
+            ConstraintRule::DisabledYearlyDates => "disabled every year",
+            ConstraintRule::DisabledUniqueDates => "disabled date",
+        })
+    }
+}
+
+/// `CheckDateConstraints` complements the boolean methods of `HasDateConstraints`
+/// with the details why a date can't be selected.
+pub trait CheckDateConstraints: HasDateConstraints {
+    /// `Ok` if the `date` can be selected, otherwise the first reason why it can't,
+    /// the default implementation only reports `DateRejection::Forbidden`
+    fn check_day(&self, date: &NaiveDate) -> Result<(), DateRejection> {
+        if self.is_day_forbidden(date) {
+            Err(DateRejection::Forbidden)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// categories of the rules that `check_day` can report, none by default
+    fn active_rules(&self) -> Vec<ConstraintRule> {
+        Vec::new()
+    }
+}
+
+/// `DateConstraints` describes which dates can't be selected.
+#[derive(Default, Debug, Builder, Clone, Getters)]
+#[builder(setter(strip_option))]
+#[getset(get = "pub")]
+#[builder(build_fn(validate = "Self::validate", error = "DateConstraintsError"))]
+pub struct DateConstraints {
+    /// earliest selectable date
+    #[builder(default)]
+    min_date: Option<NaiveDate>,
+
+    /// latest selectable date
+    #[builder(default)]
+    max_date: Option<NaiveDate>,
+
+    /// weekdays that are disabled in all weeks
+    #[builder(default)]
+    disabled_weekdays: HashSet<Weekday>,
+
+    /// months that are disabled in all years
+    #[builder(default)]
+    disabled_months: HashSet<Month>,
+
+    /// entire years that are disabled
+    #[builder(default)]
+    disabled_years: HashSet<YearNumber>,
+
+    /// particular unique dates that are disabled
+    #[builder(default)]
+    disabled_unique_dates: HashSet<NaiveDate>,
+
+    /// days of month that are disabled in all months
+    #[builder(default)]
+    disabled_monthly_dates: HashSet<DayNumber>,
+
+    /// dates that are disabled each year, the year number is ignored
+    #[builder(default)]
+    disabled_yearly_dates: Vec<NaiveDate>,
+}
+
+impl DateConstraints {
+    /// categories of the rules that can forbid a date, in the order they are checked
+    pub fn active_rules(&self) -> Vec<ConstraintRule> {
+        [
+            (
+                self.min_date.is_some() || self.max_date.is_some(),
+                ConstraintRule::OutOfRange,
+            ),
+            (
+                !self.disabled_years.is_empty(),
+                ConstraintRule::DisabledYears,
+            ),
+            (
+                !self.disabled_months.is_empty(),
+                ConstraintRule::DisabledMonths,
+            ),
+            (
+                !self.disabled_weekdays.is_empty(),
+                ConstraintRule::DisabledWeekdays,
+            ),
+            (
+                !self.disabled_monthly_dates.is_empty(),
+                ConstraintRule::DisabledMonthlyDates,
+            ),
+            (
+                !self.disabled_yearly_dates.is_empty(),
+                ConstraintRule::DisabledYearlyDates,
+            ),
+            (
+                !self.disabled_unique_dates.is_empty(),
+                ConstraintRule::DisabledUniqueDates,
+            ),
+        ]
+        .iter()
+        .filter(|(active, _)| *active)
+        .map(|(_, rule)| *rule)
+        .collect()
+    }
+
+    /// copy of the constraints with the `min_date` moved to the given date, if it is later than the current one
+    pub(crate) fn with_later_min_date(&self, min_date: NaiveDate) -> Self {
+        Self {
+            min_date: Some(
+                self.min_date
+                    .map_or(min_date, |current| current.max(min_date)),
+            ),
+            ..self.clone()
+        }
+    }
+}
+
+/// `DateConstraintsError` describes why the `DateConstraintsBuilder` couldn't build the `DateConstraints`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateConstraintsError {
+    /// the `min_date` is later than the `max_date`
+    MinAfterMax {
+        min_date: NaiveDate,
+        max_date: NaiveDate,
+    },
+    /// a field without a default value wasn't set
+    UninitializedField(&'static str),
+}
+
+impl fmt::Display for DateConstraintsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateConstraintsError::MinAfterMax { min_date, max_date } => write!(
+                f,
+                "min_date {} must be earlier or exactly at max_date {}",
+                min_date, max_date
+            ),
+            DateConstraintsError::UninitializedField(field) => write!(f, "{} must be set", field),
+        }
+    }
+}
+
+impl Error for DateConstraintsError {}
+
+impl From<UninitializedFieldError> for DateConstraintsError {
+    fn from(error: UninitializedFieldError) -> Self {
+        DateConstraintsError::UninitializedField(error.field_name())
+    }
+}
+
+impl DateConstraintsBuilder {
+    fn validate(&self) -> Result<(), DateConstraintsError> {
+        if let (Some(Some(min_date)), Some(Some(max_date))) = (self.min_date, self.max_date) {
+            if min_date > max_date {
+                return Err(DateConstraintsError::MinAfterMax { min_date, max_date });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl CheckDateConstraints for DateConstraints {
+    fn active_rules(&self) -> Vec<ConstraintRule> {
+        DateConstraints::active_rules(self)
+    }
+
+    fn check_day(&self, date: &NaiveDate) -> Result<(), DateRejection> {
+        if let Some(min_date) = self.min_date.filter(|min_date| date < min_date) {
+            return Err(DateRejection::BeforeMin(min_date));
+        }
+        if let Some(max_date) = self.max_date.filter(|max_date| date > max_date) {
+            return Err(DateRejection::AfterMax(max_date));
+        }
+        if self.disabled_years.contains(&date.year()) {
+            return Err(DateRejection::DisabledYear(date.year()));
+        }
+        let month = Month::from_u32(date.month()).expect("invalid month");
+        if self.disabled_months.contains(&month) {
+            return Err(DateRejection::DisabledMonth(month));
+        }
+        if self.disabled_weekdays.contains(&date.weekday()) {
+            return Err(DateRejection::DisabledWeekday(date.weekday()));
+        }
+        if self.disabled_monthly_dates.contains(&date.day()) {
+            return Err(DateRejection::DisabledMonthlyDate(date.day()));
+        }
+        if self
+            .disabled_yearly_dates
+            .iter()
+            .any(|disabled| disabled.month() == date.month() && disabled.day() == date.day())
+        {
+            return Err(DateRejection::DisabledYearlyDate);
+        }
+        if self.disabled_unique_dates.contains(date) {
+            return Err(DateRejection::DisabledUniqueDate);
+        }
+        Ok(())
+    }
+}
+
+impl HasDateConstraints for DateConstraints {
+    fn is_day_forbidden(&self, date: &NaiveDate) -> bool {
+        self.check_day(date).is_err()
+    }
+
+    fn is_month_forbidden(&self, year_month_info: &NaiveDate) -> bool {
+        let first_day = NaiveDate::from_ymd_opt(year_month_info.year(), year_month_info.month(), 1)
+            .expect("invalid date");
+        self.disabled_years.contains(&first_day.year())
+            || self
+                .disabled_months
+                .contains(&Month::from_u32(first_day.month()).expect("invalid month"))
+            || first_day
+                .iter_days()
+                .take_while(|date| date.month() == first_day.month())
+                .all(|date| self.is_day_forbidden(&date))
+    }
+
+    fn is_year_forbidden(&self, year: YearNumber) -> bool {
+        self.disabled_years.contains(&year)
+            || self.min_date.is_some_and(|min_date| year < min_date.year())
+            || self.max_date.is_some_and(|max_date| year > max_date.year())
+            || (1..=12u32).all(|month| {
+                self.is_month_forbidden(
+                    &NaiveDate::from_ymd_opt(year, month, 1).expect("invalid date"),
+                )
+            })
+    }
+
+    fn is_year_group_forbidden(&self, year: YearNumber) -> bool {
+        year_group_range(year).all(|year| self.is_year_forbidden(year))
+    }
+}
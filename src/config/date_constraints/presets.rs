@@ -0,0 +1,46 @@
+//! Ready-made `DateConstraintsBuilder`s for the typical business pickers,
+//! the national holiday calendars are behind the `holidays-*` features.
+use std::collections::HashSet;
+
+use chrono::prelude::*;
+
+use super::DateConstraintsBuilder;
+
+#[cfg(feature = "holidays-cz")]
+pub mod cz;
+#[cfg(feature = "holidays-us")]
+pub mod us;
+
+/// constraints with Saturdays and Sundays disabled
+pub fn weekends_disabled() -> DateConstraintsBuilder {
+    let mut builder = DateConstraintsBuilder::default();
+    builder.disabled_weekdays([Weekday::Sat, Weekday::Sun].iter().copied().collect());
+    builder
+}
+
+/// constraints with the weekends and the given `holidays` disabled
+pub fn business_days(holidays: impl IntoIterator<Item = NaiveDate>) -> DateConstraintsBuilder {
+    let mut builder = weekends_disabled();
+    builder.disabled_unique_dates(holidays.into_iter().collect::<HashSet<_>>());
+    builder
+}
+
+/// Easter Sunday of the given year in the Gregorian calendar, the anonymous Gregorian algorithm
+#[cfg(any(feature = "holidays-cz", feature = "holidays-us"))]
+pub(crate) fn easter_sunday(year: i32) -> NaiveDate {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    crate::date::ymd_clamped(year, month as u32, day as u32)
+}
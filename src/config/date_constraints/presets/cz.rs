@@ -0,0 +1,42 @@
+//! Public holidays of the Czech Republic.
+use std::ops::RangeInclusive;
+
+use chrono::{prelude::*, Duration};
+use chrono_datepicker_core::viewed_date::YearNumber;
+
+use crate::date::ymd_clamped;
+
+use super::{business_days as business_days_with, easter_sunday, DateConstraintsBuilder};
+
+/// month and day of the holidays that fall on the same date every year
+const FIXED_HOLIDAYS: [(u32, u32); 11] = [
+    (1, 1),
+    (5, 1),
+    (5, 8),
+    (7, 5),
+    (7, 6),
+    (9, 28),
+    (10, 28),
+    (11, 17),
+    (12, 24),
+    (12, 25),
+    (12, 26),
+];
+
+/// public holidays of the given year, including Good Friday and Easter Monday
+pub fn holidays(year: YearNumber) -> Vec<NaiveDate> {
+    let easter_sunday = easter_sunday(year);
+    FIXED_HOLIDAYS
+        .iter()
+        .map(|(month, day)| ymd_clamped(year, *month, *day))
+        .chain([
+            easter_sunday - Duration::days(2),
+            easter_sunday + Duration::days(1),
+        ])
+        .collect()
+}
+
+/// constraints with the weekends and the public holidays of the given `years` disabled
+pub fn business_days(years: RangeInclusive<YearNumber>) -> DateConstraintsBuilder {
+    business_days_with(years.flat_map(holidays))
+}
@@ -0,0 +1,57 @@
+//! Federal holidays of the United States.
+use std::ops::RangeInclusive;
+
+use chrono::prelude::*;
+use chrono_datepicker_core::viewed_date::YearNumber;
+
+use crate::date::ymd_clamped;
+
+use super::{business_days as business_days_with, DateConstraintsBuilder};
+
+/// federal holidays of the given year, the ones falling on a weekend are observed
+/// on the preceding Friday or the following Monday
+pub fn holidays(year: YearNumber) -> Vec<NaiveDate> {
+    vec![
+        observed(ymd_clamped(year, 1, 1)),
+        // Martin Luther King Jr. Day
+        nth_weekday(year, 1, Weekday::Mon, 3),
+        // Washington's Birthday
+        nth_weekday(year, 2, Weekday::Mon, 3),
+        // Memorial Day
+        last_weekday(year, 5, Weekday::Mon),
+        observed(ymd_clamped(year, 6, 19)),
+        observed(ymd_clamped(year, 7, 4)),
+        // Labor Day
+        nth_weekday(year, 9, Weekday::Mon, 1),
+        // Columbus Day
+        nth_weekday(year, 10, Weekday::Mon, 2),
+        observed(ymd_clamped(year, 11, 11)),
+        // Thanksgiving Day
+        nth_weekday(year, 11, Weekday::Thu, 4),
+        observed(ymd_clamped(year, 12, 25)),
+    ]
+}
+
+/// constraints with the weekends and the federal holidays of the given `years` disabled
+pub fn business_days(years: RangeInclusive<YearNumber>) -> DateConstraintsBuilder {
+    business_days_with(years.flat_map(holidays))
+}
+
+fn observed(date: NaiveDate) -> NaiveDate {
+    match date.weekday() {
+        Weekday::Sat => date.pred_opt().unwrap_or(date),
+        Weekday::Sun => date.succ_opt().unwrap_or(date),
+        _ => date,
+    }
+}
+
+/// the `n`-th `weekday` of the month, the first day of the month at the ends of the representable years
+fn nth_weekday(year: YearNumber, month: u32, weekday: Weekday, n: u8) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, n)
+        .unwrap_or_else(|| ymd_clamped(year, month, 1))
+}
+
+fn last_weekday(year: YearNumber, month: u32, weekday: Weekday) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, 5)
+        .unwrap_or_else(|| nth_weekday(year, month, weekday, 4))
+}
@@ -0,0 +1,94 @@
+//! Conversion of the configuration objects of the JS datepickers into the `PickerConfig`,
+//! enabled by the `js-config` feature.
+//!
+//! The object can have the `min`, `max` and `initialDate` ISO dates, the `disabled` array of ISO dates
+//! and the `disabledWeekdays` array of the JS weekday numbers, 0 for Sunday to 6 for Saturday,
+//! `minDate`, `maxDate` and `disable` are accepted as well:
+//!
+//! ```rust,ignore
+//! // { min: "2024-01-01", max: "2024-12-31", disabled: ["2024-12-25"], disabledWeekdays: [0, 6] }
+//! let config = PickerConfig::from_js(js_config.clone())?;
+//! let date_constraints = DateConstraints::from_js(js_config)?;
+//! ```
+use std::collections::HashSet;
+
+use chrono::{NaiveDate, Weekday};
+use seed::prelude::JsValue;
+use serde::Deserialize;
+
+use super::{
+    date_constraints::{DateConstraints, DateConstraintsBuilder},
+    ConfigError, PickerConfig, PickerConfigBuilder, ValidatedBuild,
+};
+
+/// the JS configuration object as read by serde-wasm-bindgen
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct JsConfig {
+    #[serde(alias = "minDate")]
+    min: Option<NaiveDate>,
+    #[serde(alias = "maxDate")]
+    max: Option<NaiveDate>,
+    #[serde(alias = "disable")]
+    disabled: Vec<NaiveDate>,
+    disabled_weekdays: Vec<u8>,
+    initial_date: Option<NaiveDate>,
+}
+
+/// `FromJs` converts a JS configuration object into the Rust config.
+pub trait FromJs: Sized {
+    fn from_js(value: JsValue) -> Result<Self, ConfigError>;
+}
+
+impl FromJs for PickerConfig<DateConstraints> {
+    fn from_js(value: JsValue) -> Result<Self, ConfigError> {
+        let js_config = read_js_config(value)?;
+        let mut builder = PickerConfigBuilder::default();
+        builder.date_constraints(js_date_constraints(&js_config)?);
+        if let Some(initial_date) = js_config.initial_date {
+            builder.initial_date(initial_date);
+        }
+        builder.build_validated()
+    }
+}
+
+/// only the constraints of the JS configuration object, e.g. for the `init` of the `PickerConfig::from_js`
+impl FromJs for DateConstraints {
+    fn from_js(value: JsValue) -> Result<Self, ConfigError> {
+        js_date_constraints(&read_js_config(value)?)
+    }
+}
+
+fn read_js_config(value: JsValue) -> Result<JsConfig, ConfigError> {
+    serde_wasm_bindgen::from_value(value).map_err(|error| ConfigError::InvalidJs(error.to_string()))
+}
+
+fn js_date_constraints(js_config: &JsConfig) -> Result<DateConstraints, ConfigError> {
+    let mut date_constraints = DateConstraintsBuilder::default();
+    if let Some(min_date) = js_config.min {
+        date_constraints.min_date(min_date);
+    }
+    if let Some(max_date) = js_config.max {
+        date_constraints.max_date(max_date);
+    }
+    let disabled_weekdays = js_config
+        .disabled_weekdays
+        .iter()
+        .map(|day| js_weekday(*day))
+        .collect::<Result<HashSet<Weekday>, ConfigError>>()?;
+    Ok(date_constraints
+        .disabled_weekdays(disabled_weekdays)
+        .disabled_unique_dates(js_config.disabled.iter().cloned().collect())
+        .build()?)
+}
+
+/// weekday of the JS `Date.getDay()` number, 0 is Sunday
+fn js_weekday(day: u8) -> Result<Weekday, ConfigError> {
+    if day > 6 {
+        return Err(ConfigError::InvalidJs(format!(
+            "disabledWeekdays must be between 0 (Sunday) and 6 (Saturday), got {}",
+            day
+        )));
+    }
+    Ok((0..day).fold(Weekday::Sun, |weekday, _| weekday.succ()))
+}
@@ -0,0 +1,247 @@
+//! Configuration of the datepicker, the `PickerConfig` is provided by chrono-datepicker-core,
+//! the `DateConstraints` are implemented in this crate.
+//!
+//! Only the `PickerConfig` and its builder are re-exported, the `date_constraints` module of this crate
+//! replaces the one of chrono-datepicker-core, so that there is a single `DateConstraints` type.
+pub use chrono_datepicker_core::config::{PickerConfig, PickerConfigBuilder};
+
+use std::{error::Error, fmt};
+
+use chrono::prelude::*;
+use chrono_datepicker_core::dialog_view_type::DialogViewType;
+use seed::{attrs, prelude::*, virtual_dom::Attrs};
+
+use date_constraints::HasDateConstraints;
+
+use crate::{navigation::nearest_allowed_month, range::DateRange, view_type_rank};
+
+pub mod date_constraints;
+#[cfg(feature = "js-config")]
+pub mod js;
+
+use date_constraints::{DateConstraints, DateConstraintsBuilder, DateConstraintsError};
+
+/// `PickerConfigBuilder` with all the values of the `config`, except for the `date_constraints`
+/// and the `initial_date`, which have to be consistent with each other and are set by the caller
+pub(crate) fn rebuild<T: HasDateConstraints + Default + Clone>(
+    config: &PickerConfig<T>,
+) -> PickerConfigBuilder<T> {
+    let mut builder = PickerConfigBuilder::default();
+    builder
+        .initially_opened(*config.initially_opened())
+        .initial_view_type(*config.initial_view_type())
+        .selection_type(*config.selection_type())
+        .month_title_format(config.month_title_format().clone());
+    builder
+}
+
+/// maximal age in years that can be picked in the `dob_mode`
+const DOB_MAX_AGE: i32 = 120;
+
+/// `DateOfBirthPreset` configures the `PickerConfigBuilder` for picking a date of birth.
+pub trait DateOfBirthPreset {
+    /// opens the dialog in the Years view, selects days and allows dates from 120 years ago until today
+    fn dob_mode(&mut self) -> &mut Self {
+        self.dob_mode_until(Local::now().naive_local().date())
+    }
+
+    /// `dob_mode` with the given current date, e.g. the one of the `today_provider` option
+    fn dob_mode_until(&mut self, today: NaiveDate) -> &mut Self;
+}
+
+impl DateOfBirthPreset for PickerConfigBuilder<DateConstraints> {
+    fn dob_mode_until(&mut self, today: NaiveDate) -> &mut Self {
+        self.initial_view_type(DialogViewType::Years)
+            .selection_type(DialogViewType::Days)
+            .date_constraints(dob_constraints_until(today))
+    }
+}
+
+/// constraints of the `dob_mode_until`, allowing dates from 120 years before the `today` until the `today`
+pub fn dob_constraints_until(today: NaiveDate) -> DateConstraints {
+    let min_date = NaiveDate::from_ymd_opt(today.year() - DOB_MAX_AGE, 1, 1).expect("Invalid date");
+    DateConstraintsBuilder::default()
+        .min_date(min_date)
+        .max_date(today)
+        .build()
+        .expect("min_date is earlier than max_date")
+}
+
+/// `SimpleConfig` constructs the `PickerConfig`s that only limit the selectable days,
+/// without the builders and their `unwrap`s, e.g. for the apps with many similar pickers,
+/// `DateConstraints::simple` constructs the constraints of such a config.
+pub trait SimpleConfig {
+    /// selects days between the `min_date` and the `max_date` given in any order, both included
+    fn simple(min_date: NaiveDate, max_date: NaiveDate) -> Self;
+
+    /// selects any day
+    fn unconstrained() -> Self;
+}
+
+impl SimpleConfig for PickerConfig<DateConstraints> {
+    fn simple(min_date: NaiveDate, max_date: NaiveDate) -> Self {
+        PickerConfigBuilder::default()
+            .date_constraints(DateConstraints::simple(min_date, max_date))
+            .build()
+            .expect("config without an initial date is valid")
+    }
+
+    fn unconstrained() -> Self {
+        PickerConfigBuilder::default()
+            .build()
+            .expect("config without an initial date is valid")
+    }
+}
+
+impl SimpleConfig for DateConstraints {
+    fn simple(min_date: NaiveDate, max_date: NaiveDate) -> Self {
+        let range = DateRange::new(min_date, max_date);
+        DateConstraintsBuilder::default()
+            .min_date(range.start())
+            .max_date(range.end())
+            .build()
+            .expect("min_date is earlier than max_date")
+    }
+
+    fn unconstrained() -> Self {
+        DateConstraints::default()
+    }
+}
+
+/// `HtmlDateAttrs` derives the attributes of an input associated with the datepicker from its config
+/// and the constraints it was built with, which the config doesn't expose.
+pub trait HtmlDateAttrs {
+    /// `min`, `max` and `step` attributes blocking the values outside of the `min_date` and `max_date`
+    /// of the `date_constraints`, formatted for the `date`, `month` or `number` input of the `selection_type`
+    fn html_date_attrs(&self, date_constraints: &DateConstraints) -> Attrs;
+}
+
+impl HtmlDateAttrs for PickerConfig<DateConstraints> {
+    fn html_date_attrs(&self, date_constraints: &DateConstraints) -> Attrs {
+        let format = match self.selection_type() {
+            DialogViewType::Days => "%Y-%m-%d",
+            DialogViewType::Months => "%Y-%m",
+            DialogViewType::Years => "%Y",
+        };
+        let mut html_attrs = attrs! { At::Step => "1" };
+        if let Some(min_date) = date_constraints.min_date() {
+            html_attrs.add(At::Min, min_date.format(format).to_string());
+        }
+        if let Some(max_date) = date_constraints.max_date() {
+            html_attrs.add(At::Max, max_date.format(format).to_string());
+        }
+        html_attrs
+    }
+}
+
+/// `ConfigError` describes why a `PickerConfig` can't be used with its date constraints.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigError {
+    /// the `PickerConfigBuilder` itself failed, with its message
+    Builder(String),
+    /// the `initial_view_type` displays smaller periods than the `selection_type` selects,
+    /// e.g. the days when only the months can be selected; the `PickerConfigBuilder` rejects it before `validate`,
+    /// so `ValidatedBuild` reports it as `Builder`
+    InitialViewTooDetailed {
+        initial_view_type: DialogViewType,
+        selection_type: DialogViewType,
+    },
+    /// the day, month or year of the `initial_date`, depending on the `selection_type`, is forbidden
+    InitialDateForbidden(NaiveDate),
+    /// nothing can be selected within a century from the `initial_date` or today,
+    /// e.g. all the years are forbidden with the `selection_type` Years
+    NothingSelectable,
+    /// the `DateConstraintsBuilder` failed, e.g. the `min_date` is later than the `max_date`
+    Constraints(DateConstraintsError),
+    /// the JS configuration object of the `js-config` feature couldn't be read, see `js::FromJs`
+    InvalidJs(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Builder(message) => f.write_str(message),
+            ConfigError::InitialViewTooDetailed {
+                initial_view_type,
+                selection_type,
+            } => write!(
+                f,
+                "initial_view_type {:?} displays smaller periods than the selection_type {:?}",
+                initial_view_type, selection_type
+            ),
+            ConfigError::InitialDateForbidden(initial_date) => {
+                write!(f, "initial_date {} is forbidden", initial_date)
+            }
+            ConfigError::NothingSelectable => f.write_str("no date can be selected"),
+            ConfigError::Constraints(error) => write!(f, "invalid date constraints: {}", error),
+            ConfigError::InvalidJs(message) => write!(f, "invalid JS config: {}", message),
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+impl From<DateConstraintsError> for ConfigError {
+    fn from(error: DateConstraintsError) -> Self {
+        ConfigError::Constraints(error)
+    }
+}
+
+/// Checks that the `initial_view_type`, the `selection_type` and the `initial_date` of the `config`
+/// are consistent with its date constraints, beyond the `initial_date` check of the `PickerConfigBuilder`.
+pub fn validate<T: HasDateConstraints + Default + Clone>(
+    config: &PickerConfig<T>,
+) -> Result<(), ConfigError> {
+    validate_on(config, Local::now().naive_local().date())
+}
+
+/// `validate` with the given current date, from which the selectable dates are searched
+/// when the `config` has no `initial_date`, e.g. the one of the `today_provider` option
+pub fn validate_on<T: HasDateConstraints + Default + Clone>(
+    config: &PickerConfig<T>,
+    today: NaiveDate,
+) -> Result<(), ConfigError> {
+    let (initial_view_type, selection_type) =
+        (*config.initial_view_type(), *config.selection_type());
+    if view_type_rank(&initial_view_type) > view_type_rank(&selection_type) {
+        return Err(ConfigError::InitialViewTooDetailed {
+            initial_view_type,
+            selection_type,
+        });
+    }
+    if let Some(initial_date) = config.initial_date() {
+        let forbidden = match selection_type {
+            DialogViewType::Days => config.is_day_forbidden(initial_date),
+            DialogViewType::Months => config.is_month_forbidden(initial_date),
+            DialogViewType::Years => config.is_year_forbidden(initial_date.year()),
+        };
+        if forbidden {
+            return Err(ConfigError::InitialDateForbidden(*initial_date));
+        }
+    }
+    let start = config.initial_date().unwrap_or(today);
+    if config.is_month_forbidden(&nearest_allowed_month(config, &start)) {
+        return Err(ConfigError::NothingSelectable);
+    }
+    Ok(())
+}
+
+/// `ValidatedBuild` builds a `PickerConfig` checked by `validate`.
+pub trait ValidatedBuild<T: HasDateConstraints + Default + Clone> {
+    fn build_validated(&self) -> Result<PickerConfig<T>, ConfigError> {
+        self.build_validated_on(Local::now().naive_local().date())
+    }
+
+    /// `build_validated` checked by `validate_on` with the given current date
+    fn build_validated_on(&self, today: NaiveDate) -> Result<PickerConfig<T>, ConfigError>;
+}
+
+impl<T: HasDateConstraints + Default + Clone> ValidatedBuild<T> for PickerConfigBuilder<T> {
+    fn build_validated_on(&self, today: NaiveDate) -> Result<PickerConfig<T>, ConfigError> {
+        let config = self
+            .build()
+            .map_err(|error| ConfigError::Builder(error.to_string()))?;
+        validate_on(&config, today)?;
+        Ok(config)
+    }
+}
@@ -0,0 +1,77 @@
+//! Coordination of independent datepickers, so that only one of their dialogs is open at a time.
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+    rc::Rc,
+};
+
+/// function closing the dialog of a registered picker
+type CloseFn = Rc<dyn Fn()>;
+
+/// `OpenCoordinator` is a shared handle, the pickers that get a clone of it in their `PickerOptions`
+/// close their dialogs when another one of them opens.
+#[derive(Clone, Default)]
+pub struct OpenCoordinator {
+    closers: Rc<RefCell<Vec<(usize, CloseFn)>>>,
+    next_id: Rc<Cell<usize>>,
+}
+
+impl OpenCoordinator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// registers a picker, `close` is called when a dialog of another registered picker opens
+    pub(crate) fn register(&self, close: impl Fn() + 'static) -> OpenRegistration {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        self.closers.borrow_mut().push((id, Rc::new(close)));
+        OpenRegistration {
+            coordinator: self.clone(),
+            id,
+        }
+    }
+
+    fn close_others(&self, opened_id: usize) {
+        let closers: Vec<CloseFn> = self
+            .closers
+            .borrow()
+            .iter()
+            .filter(|(id, _)| *id != opened_id)
+            .map(|(_, close)| close.clone())
+            .collect();
+        for close in closers {
+            close();
+        }
+    }
+}
+
+impl fmt::Debug for OpenCoordinator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OpenCoordinator")
+            .field("registered", &self.closers.borrow().len())
+            .finish()
+    }
+}
+
+/// `OpenRegistration` of a picker in the `OpenCoordinator`, the picker is unregistered when it gets dropped.
+pub(crate) struct OpenRegistration {
+    coordinator: OpenCoordinator,
+    id: usize,
+}
+
+impl OpenRegistration {
+    /// closes the dialogs of the other registered pickers
+    pub(crate) fn opened(&self) {
+        self.coordinator.close_others(self.id);
+    }
+}
+
+impl Drop for OpenRegistration {
+    fn drop(&mut self) {
+        self.coordinator
+            .closers
+            .borrow_mut()
+            .retain(|(id, _)| *id != self.id);
+    }
+}
@@ -0,0 +1,126 @@
+use chrono::{Datelike, Duration, NaiveDate};
+use chrono_datepicker_core::viewed_date::ViewedDate;
+
+use crate::{error::DatePickerError, navigation::YEARS_IN_YEAR_GROUP};
+#[cfg(feature = "time")]
+use std::convert::TryFrom;
+
+/// `PickerDate` is a date type that can be exchanged with the datepicker,
+/// the datepicker itself stores chrono `NaiveDate`s.
+///
+/// With the `time` feature it is also implemented for `time::Date`, so that an app can pass `time::Date`s
+/// through `Msg::date_selected` and receive them from `update_as`, `update_range_as`, `Model::selected_date_as`
+/// and `Model::selected_range_as`. The feature doesn't replace `NaiveDate` in the rest of the public API:
+/// cargo features are additive, so another crate enabling it in the same build mustn't change the types
+/// its chrono users see, and the `PickerConfig` and the date constraints of chrono-datepicker-core
+/// are typed with `NaiveDate`, convert their dates with `PickerDate::to_naive_date`.
+pub trait PickerDate: Sized {
+    /// `None` if the date can't be represented as a `NaiveDate`
+    fn to_naive_date(&self) -> Option<NaiveDate>;
+
+    /// `None` if the `date` can't be represented by this type
+    fn from_naive_date(date: NaiveDate) -> Option<Self>;
+}
+
+impl PickerDate for NaiveDate {
+    fn to_naive_date(&self) -> Option<NaiveDate> {
+        Some(*self)
+    }
+
+    fn from_naive_date(date: NaiveDate) -> Option<Self> {
+        Some(date)
+    }
+}
+
+/// Date of the `year`, `month` and `day`, an error instead of the panic of `NaiveDate::from_ymd`.
+pub fn try_ymd(year: i32, month: u32, day: u32) -> Result<NaiveDate, DatePickerError> {
+    if year < NaiveDate::MIN.year() || year > NaiveDate::MAX.year() {
+        return Err(DatePickerError::YearOutOfRange(year));
+    }
+    NaiveDate::from_ymd_opt(year, month, day).ok_or(DatePickerError::InvalidDate {
+        year,
+        month,
+        day,
+    })
+}
+
+/// Date of the `year`, `month` and `day` that never panics: the year is clamped to the years chrono
+/// can represent, the month to 1–12 and the day to the length of the month, e.g. February 30th is February 28/29.
+pub fn ymd_clamped(year: i32, month: u32, day: u32) -> NaiveDate {
+    let year = year.clamp(NaiveDate::MIN.year(), NaiveDate::MAX.year());
+    let month = month.clamp(1, 12);
+    (1..=day.clamp(1, 31))
+        .rev()
+        .find_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+        .expect("The first day of every month is valid")
+}
+
+/// the date at which a jump by the `offset` out of the range of `NaiveDate` saturates
+pub(crate) fn saturated_date(offset: i32) -> NaiveDate {
+    if offset < 0 {
+        NaiveDate::MIN
+    } else {
+        NaiveDate::MAX
+    }
+}
+
+/// `ViewedDateArithmetic` complements the navigation of the `ViewedDate` by a single period
+/// with jumps by any number of weeks, months, years or year groups.
+///
+/// Unlike the `ViewedDate` navigation, the jumps saturate at `NaiveDate::MIN` and `NaiveDate::MAX`
+/// instead of panicking, e.g. in the historical archives going back to the earliest dates chrono supports.
+pub trait ViewedDateArithmetic: ViewedDate {
+    /// date `weeks` weeks away
+    fn add_weeks(&self, weeks: i32) -> NaiveDate;
+
+    /// date `months` months away, the day is clamped to the length of the target month (Jan 31 + 1 month = Feb 28/29)
+    fn add_months(&self, months: i32) -> NaiveDate;
+
+    /// date `years` years away, the day is clamped to the length of the target month (Feb 29 + 1 year = Feb 28)
+    fn add_years(&self, years: i32) -> NaiveDate;
+
+    /// date in the year group `groups` groups away, as displayed by the years view
+    fn add_year_groups(&self, groups: i32) -> NaiveDate;
+}
+
+impl ViewedDateArithmetic for NaiveDate {
+    fn add_weeks(&self, weeks: i32) -> NaiveDate {
+        self.checked_add_signed(Duration::weeks(i64::from(weeks)))
+            .unwrap_or(saturated_date(weeks))
+    }
+
+    fn add_months(&self, months: i32) -> NaiveDate {
+        let month_index = (self.year() * 12 + self.month0() as i32)
+            .saturating_add(months)
+            .clamp(NaiveDate::MIN.year() * 12, NaiveDate::MAX.year() * 12 + 11);
+        let (year, month) = (
+            month_index.div_euclid(12),
+            month_index.rem_euclid(12) as u32 + 1,
+        );
+        ymd_clamped(year, month, self.day())
+    }
+
+    fn add_years(&self, years: i32) -> NaiveDate {
+        self.add_months(years.saturating_mul(12))
+    }
+
+    fn add_year_groups(&self, groups: i32) -> NaiveDate {
+        self.add_years(groups.saturating_mul(YEARS_IN_YEAR_GROUP))
+    }
+}
+
+#[cfg(feature = "time")]
+impl PickerDate for time::Date {
+    fn to_naive_date(&self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(
+            self.year(),
+            u8::from(self.month()).into(),
+            self.day().into(),
+        )
+    }
+
+    fn from_naive_date(date: NaiveDate) -> Option<Self> {
+        let month = time::Month::try_from(u8::try_from(date.month()).ok()?).ok()?;
+        time::Date::from_calendar_date(date.year(), month, u8::try_from(date.day()).ok()?).ok()
+    }
+}
@@ -0,0 +1,156 @@
+//! Text input for typing a date by hand, masked by a pattern like `DD/MM/YYYY`.
+//!
+//! Only the digits of the typed value are kept, the separators of the pattern are inserted
+//! as soon as the next digit is typed, so the app stores the masked value and sends the `Msg`
+//! of `DateInputMask::selection_msg` once the value is a complete date that can be selected:
+//!
+//! ```rust,ignore
+//! Msg::DateTyped(value) => {
+//!     if let Some(msg) = model.mask.selection_msg(&value, &model.date_picker) {
+//!         orders.send_msg(Msg::DatePicker(msg));
+//!     }
+//!     model.typed_date = value;
+//! }
+//! // in the view
+//! model.mask.view(&model.typed_date, Msg::DateTyped)
+//! ```
+use chrono::{Datelike, NaiveDate};
+use seed::{prelude::*, *};
+use std::convert::TryFrom;
+
+use crate::{config::date_constraints::CheckDateConstraints, Model, Msg};
+
+/// `DateInputMask` describes the pattern of a manually typed date, `DD` stands for the day,
+/// `MM` for the month, `YYYY` for the year and any other character is a separator,
+/// e.g. `DD/MM/YYYY`, `YYYY-MM-DD` or `MM.DD.YYYY`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateInputMask {
+    pattern: Vec<char>,
+}
+
+impl DateInputMask {
+    pub fn new(pattern: &str) -> Self {
+        Self {
+            pattern: pattern.chars().collect(),
+        }
+    }
+
+    /// the pattern as displayed in the placeholder of the input
+    pub fn pattern(&self) -> String {
+        self.pattern.iter().collect()
+    }
+
+    /// masks the `typed` value: drops everything but the digits, inserts the separators before the next digit
+    /// and pads a day starting with 4-9 or a month starting with 2-9 with a zero, e.g. `5` in `DD` becomes `05`
+    pub fn apply(&self, typed: &str) -> String {
+        let mut digits = typed.chars().filter(char::is_ascii_digit).peekable();
+        let mut masked = String::new();
+        let mut position = 0;
+        while position < self.pattern.len() && digits.peek().is_some() {
+            let placeholder = self.pattern[position];
+            if !is_placeholder(placeholder) {
+                masked.push(placeholder);
+                position += 1;
+                continue;
+            }
+            let digit = digits.next().expect("Digit was peeked");
+            let segment_start = position == 0 || self.pattern[position - 1] != placeholder;
+            let max_first_digit = match placeholder {
+                'D' => '3',
+                'M' => '1',
+                _ => '9',
+            };
+            if segment_start
+                && digit > max_first_digit
+                && self.pattern.get(position + 1) == Some(&placeholder)
+            {
+                masked.push('0');
+                position += 1;
+            }
+            masked.push(digit);
+            position += 1;
+        }
+        masked
+    }
+
+    /// date of the complete masked `value`, `None` if it isn't complete or it isn't a valid date
+    pub fn parse(&self, value: &str) -> Option<NaiveDate> {
+        let value: Vec<char> = value.chars().collect();
+        if value.len() != self.pattern.len() {
+            return None;
+        }
+        let (mut day, mut month, mut year) = (0, 0, 0);
+        for (placeholder, character) in self.pattern.iter().copied().zip(value) {
+            let number = match placeholder {
+                'D' => &mut day,
+                'M' => &mut month,
+                'Y' => &mut year,
+                separator if separator == character => continue,
+                _ => return None,
+            };
+            *number = *number * 10 + character.to_digit(10)? as i32;
+        }
+        NaiveDate::from_ymd_opt(year, u32::try_from(month).ok()?, u32::try_from(day).ok()?)
+    }
+
+    /// the `date` written in the pattern, e.g. to fill the input after the date was picked in the dialog
+    pub fn format(&self, date: &NaiveDate) -> String {
+        let mut formatted = String::new();
+        let mut position = 0;
+        while position < self.pattern.len() {
+            let placeholder = self.pattern[position];
+            let width = self.pattern[position..]
+                .iter()
+                .take_while(|character| **character == placeholder)
+                .count();
+            let number = match placeholder {
+                'D' => date.day() as i32,
+                'M' => date.month() as i32,
+                'Y' => date.year(),
+                separator => {
+                    formatted.push(separator);
+                    position += 1;
+                    continue;
+                }
+            };
+            formatted.push_str(&format!("{:0width$}", number, width = width));
+            position += width;
+        }
+        formatted
+    }
+
+    /// `Msg::DateSelected` of the masked `value` if it is a complete date that can be selected in the `model`
+    pub fn selection_msg<T: CheckDateConstraints + Default + Clone>(
+        &self,
+        value: &str,
+        model: &Model<T>,
+    ) -> Option<Msg> {
+        self.parse(value)
+            .filter(|date| model.validate(*date).is_ok())
+            .map(Msg::DateSelected)
+    }
+
+    /// text input displaying the masked `value`, `on_input` gets the newly typed value already masked
+    pub fn view<Ms: 'static>(
+        &self,
+        value: &str,
+        on_input: impl FnOnce(String) -> Ms + Clone + 'static,
+    ) -> Node<Ms> {
+        let mask = self.clone();
+        input![
+            attrs! {
+                At::Type => "text",
+                At::from("inputmode") => "numeric",
+                At::AutoComplete => "off",
+                At::Placeholder => self.pattern(),
+                At::MaxLength => self.pattern.len(),
+                At::Value => value,
+            },
+            input_ev(Ev::Input, move |typed| on_input(mask.apply(&typed))),
+        ]
+    }
+}
+
+fn is_placeholder(character: char) -> bool {
+    matches!(character, 'D' | 'M' | 'Y')
+}
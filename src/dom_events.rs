@@ -0,0 +1,28 @@
+//! DOM `CustomEvent`s dispatched with the `dom_events` option, so that the page can listen to the picker
+//! without any Rust glue. They are dispatched from the `datepicker-events` element wrapping the dialog,
+//! which stays rendered while the dialog is closed, so that the changes made without the dialog are reported too.
+use chrono::NaiveDate;
+use seed::prelude::*;
+
+/// dispatched when the dialog gets displayed
+pub const OPEN_EVENT: &str = "datepicker-open";
+
+/// dispatched when the selection changes, the `detail` is the newly selected ISO date or `null`
+pub const CHANGE_EVENT: &str = "datepicker-change";
+
+/// dispatched when the dialog gets closed
+pub const CLOSE_EVENT: &str = "datepicker-close";
+
+/// dispatches the `name` event bubbling out of the shadow roots with the ISO `date` in its `detail`
+pub(crate) fn dispatch(element: &web_sys::HtmlElement, name: &str, date: Option<NaiveDate>) {
+    let detail = date.map_or(JsValue::NULL, |date| {
+        JsValue::from_str(&date.format("%Y-%m-%d").to_string())
+    });
+    let event_init = web_sys::CustomEventInit::new();
+    event_init.set_bubbles(true);
+    event_init.set_composed(true);
+    event_init.set_detail(&detail);
+    if let Ok(event) = web_sys::CustomEvent::new_with_event_init_dict(name, &event_init) {
+        element.dispatch_event(&event).ok();
+    }
+}
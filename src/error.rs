@@ -0,0 +1,23 @@
+use std::fmt;
+
+/// `DatePickerError` describes why a date couldn't be constructed from its parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatePickerError {
+    /// the year, month and day don't make up a date, e.g. February 30th
+    InvalidDate { year: i32, month: u32, day: u32 },
+    /// the year is outside of the years chrono can represent
+    YearOutOfRange(i32),
+}
+
+impl fmt::Display for DatePickerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DatePickerError::InvalidDate { year, month, day } => {
+                write!(f, "{}-{:02}-{:02} is not a valid date", year, month, day)
+            }
+            DatePickerError::YearOutOfRange(year) => write!(f, "year {} is out of range", year),
+        }
+    }
+}
+
+impl std::error::Error for DatePickerError {}
@@ -0,0 +1,218 @@
+use std::fmt::Write;
+
+use chrono::prelude::*;
+use chrono_datepicker_core::{
+    config::{date_constraints::HasDateConstraints, PickerConfig},
+    dialog_view_type::DialogViewType,
+    utils::create_dialog_title_text,
+    viewed_date::YearNumber,
+};
+
+use crate::{
+    calendar::{CalendarSystem, GregorianCalendar},
+    date::ymd_clamped,
+    navigation::{fiscal_year_first_month, month_offset, year_group_range},
+    options::{DayLabelFormat, MonthLabelFormat, PickerOptions},
+};
+
+/// Formats the `date` the same way as the dialog with the `options` displays it for the `selection_type` of the `config`:
+///
+/// - `DialogViewType::Years`: the year number as displayed by the `year_display` option, e.g. `2024`
+/// - `DialogViewType::Months`: the title of the days view of the month, e.g. `Mar 2024`
+/// - `DialogViewType::Days`: the day number of the `calendar_system` followed by the title of the days view,
+///   e.g. `5 Mar 2024`
+pub fn format_for_display<T: HasDateConstraints + Default + Clone>(
+    date: &NaiveDate,
+    config: &PickerConfig<T>,
+    options: &PickerOptions,
+) -> String {
+    match config.selection_type() {
+        DialogViewType::Years => display_year(date.year(), options),
+        DialogViewType::Months => format_title(&DialogViewType::Days, date, config, options),
+        DialogViewType::Days => format!(
+            "{} {}",
+            calendar(options).calendar_date(date).day,
+            format_title(&DialogViewType::Days, date, config, options)
+        ),
+    }
+}
+
+/// `ValueFormat` of the selection returned by `Model::formatted_value`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValueFormat {
+    /// chrono format string, e.g. `%e %b %Y`
+    Pattern(String),
+    /// chrono format string with the month and weekday names of the `Locale`, needs the `locales` feature
+    #[cfg(feature = "locales")]
+    Localized(String, chrono::Locale),
+}
+
+impl ValueFormat {
+    /// the `date` written in the format, `None` if the pattern can't format a date,
+    /// e.g. because of an unknown specifier or a time one like `%H`
+    pub fn format(&self, date: &NaiveDate) -> Option<String> {
+        let mut text = String::new();
+        let written = match self {
+            ValueFormat::Pattern(pattern) => write!(text, "{}", date.format(pattern)),
+            #[cfg(feature = "locales")]
+            ValueFormat::Localized(pattern, locale) => {
+                write!(text, "{}", date.format_localized(pattern, *locale))
+            }
+        };
+        written.ok().map(|_| text)
+    }
+
+    /// whether the pattern can format the dates, checked by the `PickerOptionsBuilder`
+    pub fn is_valid(&self) -> bool {
+        self.format(&ymd_clamped(2000, 1, 1)).is_some()
+    }
+}
+
+impl Default for ValueFormat {
+    fn default() -> Self {
+        ValueFormat::Pattern("%Y-%m-%d".to_owned())
+    }
+}
+
+impl From<&str> for ValueFormat {
+    fn from(pattern: &str) -> Self {
+        ValueFormat::Pattern(pattern.to_owned())
+    }
+}
+
+/// Formats the title of the dialog with the given `DialogViewType` viewing the `viewed_date`, the same way
+/// as the dialog with the `options` does, the `title_formatter` option wins over the default formatting.
+pub fn format_title<T: HasDateConstraints + Default + Clone>(
+    dialog_view_type: &DialogViewType,
+    viewed_date: &NaiveDate,
+    config: &PickerConfig<T>,
+    options: &PickerOptions,
+) -> String {
+    if let Some(title_formatter) = options.title_formatter() {
+        return title_formatter(*dialog_view_type, *viewed_date);
+    }
+    match (
+        dialog_view_type,
+        options.calendar_system().as_deref(),
+        options.fiscal_year_start(),
+        options.year_display(),
+    ) {
+        (DialogViewType::Years, _, _, _) => {
+            let years = year_group_range(viewed_date.year());
+            format!(
+                "{} - {}",
+                display_year(*years.start(), options),
+                display_year(*years.end(), options)
+            )
+        }
+        (DialogViewType::Days, Some(calendar), _, _) => {
+            let viewed_date = calendar.calendar_date(viewed_date);
+            format!(
+                "{} {}",
+                calendar.month_name(viewed_date.year, viewed_date.month),
+                viewed_date.year
+            )
+        }
+        (DialogViewType::Months, Some(calendar), _, _) => {
+            calendar.calendar_date(viewed_date).year.to_string()
+        }
+        (DialogViewType::Months, None, Some(fiscal_year_start), _) => {
+            format_fiscal_year_title(viewed_date, *fiscal_year_start, config)
+        }
+        (DialogViewType::Months, None, None, Some(year_display)) => {
+            year_display.display_year(viewed_date.year())
+        }
+        (DialogViewType::Days, None, _, Some(year_display)) => {
+            let displayed_year = year_display
+                .display_year(viewed_date.year())
+                .replace('%', "%%");
+            create_dialog_title_text(
+                dialog_view_type,
+                viewed_date,
+                &config.month_title_format().replace("%Y", &displayed_year),
+            )
+        }
+        _ => create_dialog_title_text(dialog_view_type, viewed_date, config.month_title_format()),
+    }
+}
+
+/// the `year` as displayed in the years view with the `year_display` option
+pub(crate) fn display_year(year: YearNumber, options: &PickerOptions) -> String {
+    options.year_display().as_ref().map_or_else(
+        || year.to_string(),
+        |year_display| year_display.display_year(year),
+    )
+}
+
+/// content of the day cell of the `date`, according to the `day_label_format` option
+pub(crate) fn day_label(date: &NaiveDate, options: &PickerOptions) -> String {
+    let calendar_date = calendar(options).calendar_date(date);
+    match options.day_label_format() {
+        DayLabelFormat::Numeric => calendar_date.day.to_string(),
+        DayLabelFormat::TwoDigit => format!("{:02}", calendar_date.day),
+        DayLabelFormat::MonthOnFirstDay if calendar_date.day == 1 => {
+            format!("1 {}", short_month_name(date, options))
+        }
+        DayLabelFormat::MonthOnFirstDay => calendar_date.day.to_string(),
+        DayLabelFormat::Custom(label) => label(date),
+    }
+}
+
+/// content of the month cell of the month containing the `date`, according to the `month_label_format` option
+pub(crate) fn month_label(date: &NaiveDate, options: &PickerOptions) -> String {
+    let calendar = calendar(options);
+    let calendar_date = calendar.calendar_date(date);
+    match options.month_label_format() {
+        MonthLabelFormat::Numeric => calendar_date.month.to_string(),
+        MonthLabelFormat::Short => short_month_name(date, options),
+        MonthLabelFormat::Long => calendar.month_name(calendar_date.year, calendar_date.month),
+        MonthLabelFormat::Custom(label) => label(date),
+    }
+}
+
+/// first three letters of the name of the month containing the `date`
+fn short_month_name(date: &NaiveDate, options: &PickerOptions) -> String {
+    let calendar = calendar(options);
+    let calendar_date = calendar.calendar_date(date);
+    calendar
+        .month_name(calendar_date.year, calendar_date.month)
+        .chars()
+        .take(3)
+        .collect()
+}
+
+/// the `calendar_system` option or the Gregorian calendar
+pub(crate) fn calendar(options: &PickerOptions) -> &dyn CalendarSystem {
+    options
+        .calendar_system()
+        .as_deref()
+        .unwrap_or(&GregorianCalendar)
+}
+
+/// Formats the title of the `DialogViewType::Months` displaying the fiscal year that contains the `viewed_date`,
+/// the fiscal year is named after the year it starts in, e.g. `FY2024: Apr 2024 – Mar 2025`.
+pub fn format_fiscal_year_title<T: HasDateConstraints + Default + Clone>(
+    viewed_date: &NaiveDate,
+    fiscal_year_start: Month,
+    config: &PickerConfig<T>,
+) -> String {
+    let first_month = fiscal_year_first_month(viewed_date, fiscal_year_start);
+    let last_month = month_offset(&first_month, 11).unwrap_or(first_month);
+    format!(
+        "FY{}: {} – {}",
+        first_month.year(),
+        first_month.format(config.month_title_format()),
+        last_month.format(config.month_title_format())
+    )
+}
+
+/// Formats the `date` as an ISO 8601 week date, e.g. `2024-W15-3` for Wednesday of the week 15,
+/// the year is the ISO week-numbering year that may differ from the calendar one around the new year.
+pub fn format_iso_week_date(date: &NaiveDate) -> String {
+    date.format("%G-W%V-%u").to_string()
+}
+
+/// Formats the `date` as an ISO 8601 ordinal date, e.g. `2024-105` for the 105th day of 2024.
+pub fn format_ordinal_date(date: &NaiveDate) -> String {
+    date.format("%Y-%j").to_string()
+}
@@ -0,0 +1,855 @@
+//! Renderer-agnostic core of the datepicker state machine, it doesn't depend on Seed,
+//! so it can drive the other frontends and be tested natively without wasm.
+//!
+//! `SelectionState` holds the selected date, the selected range, the unconfirmed parts of the selection
+//! and the undo history. Its transitions only change the state and return the new selection,
+//! the effects like closing the dialog are left to the renderer, e.g. the Seed `Model` delegates its selection here.
+//!
+//! `ViewState` holds the displayed page, the `DialogViewType` and the viewed date, and its `ViewMsg` transitions
+//! implement the navigation between the pages and the zooming, checked against the config and the options.
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use chrono_datepicker_core::{
+    config::{date_constraints::HasDateConstraints, PickerConfig},
+    dialog_view_type::DialogViewType,
+    viewed_date::{MonthNumber, YearNumber},
+};
+
+use crate::{
+    calendar::CalendarDate,
+    date::{saturated_date, ymd_clamped, ViewedDateArithmetic},
+    format::{calendar, day_label, display_year, format_title, month_label},
+    navigation::{fiscal_year_first_month, initial_viewed_date, page_bounds, year_group_range},
+    options::{DaysLayout, PickerOptions},
+    range::DateRange,
+    smaller_view_type, view_type_rank, week_start,
+};
+
+/// selected date and selected range, as stored in the undo history
+pub type Selection = (Option<NaiveDate>, Option<DateRange>);
+
+/// number of the previous selections that can be restored with `SelectionState::undo`
+pub const SELECTION_HISTORY_LENGTH: usize = 20;
+
+/// `SelectionState` describes the selection of a datepicker independently of its view.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SelectionState {
+    /// value of the date that is selected
+    pub(crate) selected_date: Option<NaiveDate>,
+
+    /// date that is staged until it gets confirmed, only used with `confirm_selection`
+    pub(crate) pending_date: Option<NaiveDate>,
+
+    /// selected range of dates (start, end), only used with `range_selection`
+    pub(crate) selected_range: Option<DateRange>,
+
+    /// start of the range that waits for its end to be clicked, only used with `range_selection`
+    pub(crate) range_start: Option<NaiveDate>,
+
+    /// range that is staged until it gets confirmed, only used with `range_selection` and `confirm_selection`
+    pub(crate) pending_range: Option<DateRange>,
+
+    /// previous selections restored by `undo`, the latest one is the last
+    undo_history: Vec<Selection>,
+
+    /// selections undone by `undo`, restored by `redo`
+    redo_history: Vec<Selection>,
+}
+
+impl SelectionState {
+    pub fn new(selected_date: Option<NaiveDate>) -> Self {
+        Self {
+            selected_date,
+            ..Self::default()
+        }
+    }
+
+    /// selected value of the datepicker
+    pub fn selected_date(&self) -> &Option<NaiveDate> {
+        &self.selected_date
+    }
+
+    /// date staged in the dialog that still waits for the confirmation
+    pub fn pending_date(&self) -> &Option<NaiveDate> {
+        &self.pending_date
+    }
+
+    /// selected range of dates (start, end), only used with `range_selection`
+    pub fn selected_range(&self) -> &Option<DateRange> {
+        &self.selected_range
+    }
+
+    /// start of the range that waits for its end to be clicked
+    pub fn range_start(&self) -> &Option<NaiveDate> {
+        &self.range_start
+    }
+
+    /// range staged in the dialog that still waits for the confirmation
+    pub fn pending_range(&self) -> &Option<DateRange> {
+        &self.pending_range
+    }
+
+    /// range displayed as selected, the pending one takes precedence
+    pub fn displayed_range(&self) -> Option<DateRange> {
+        self.pending_range.or(self.selected_range)
+    }
+
+    /// current selected date and selected range
+    pub fn selection(&self) -> Selection {
+        (self.selected_date, self.selected_range)
+    }
+
+    /// value reported to the app, the end of the selected range in the range mode
+    pub fn reported(&self, range_selection: bool) -> Option<NaiveDate> {
+        if range_selection {
+            self.selected_range.map(|range| range.end())
+        } else {
+            self.selected_date
+        }
+    }
+
+    /// date displayed as selected, the pending one takes precedence
+    pub fn displayed(&self) -> Option<NaiveDate> {
+        self.pending_date.or(self.selected_date)
+    }
+
+    /// whether there is a previous selection to restore with `undo`
+    pub fn can_undo(&self) -> bool {
+        !self.undo_history.is_empty()
+    }
+
+    /// whether there is an undone selection to restore with `redo`
+    pub fn can_redo(&self) -> bool {
+        !self.redo_history.is_empty()
+    }
+
+    /// selects the single `date`, the previous selection can be undone,
+    /// selecting the already selected date doesn't add it to the undo history
+    pub fn select(&mut self, date: NaiveDate) {
+        if self.selected_date != Some(date) {
+            self.remember();
+        }
+        self.selected_date = Some(date);
+    }
+
+    /// selects the `date`, or clears the selection if the `date` is already selected, returns the new selection
+    pub fn toggle(&mut self, date: NaiveDate) -> Option<NaiveDate> {
+        self.remember();
+        self.selected_date = Some(date).filter(|_| self.selected_date != Some(date));
+        self.selected_date
+    }
+
+    /// stages the `date` until `confirm` is called
+    pub fn stage(&mut self, date: NaiveDate) {
+        self.pending_date = Some(date);
+    }
+
+    /// stages the `date`, or unstages it if it is already staged
+    pub fn toggle_staged(&mut self, date: NaiveDate) {
+        self.pending_date = Some(date).filter(|_| self.pending_date != Some(date));
+    }
+
+    /// selects the staged date, returns it if there was one
+    pub fn confirm(&mut self) -> Option<NaiveDate> {
+        let pending_date = self.pending_date.take()?;
+        self.select(pending_date);
+        Some(pending_date)
+    }
+
+    /// starts a range at the `date`, or ends the started one there, returns the range once it is complete
+    pub fn select_range_end(&mut self, date: NaiveDate) -> Option<DateRange> {
+        match self.range_start.take() {
+            Some(range_start) => {
+                self.remember();
+                let range = DateRange::new(range_start, date);
+                self.selected_range = Some(range);
+                Some(range)
+            }
+            None => {
+                self.range_start = Some(date);
+                None
+            }
+        }
+    }
+
+    /// starts a range at the `date`, or ends the started one there and stages it until `confirm_range` is called,
+    /// returns the staged range once it is complete
+    pub fn stage_range_end(&mut self, date: NaiveDate) -> Option<DateRange> {
+        match self.range_start.take() {
+            Some(range_start) => {
+                let range = DateRange::new(range_start, date);
+                self.pending_range = Some(range);
+                Some(range)
+            }
+            None => {
+                self.pending_range = None;
+                self.range_start = Some(date);
+                None
+            }
+        }
+    }
+
+    /// selects the staged range, returns it if there was one
+    pub fn confirm_range(&mut self) -> Option<DateRange> {
+        let pending_range = self.pending_range.take()?;
+        self.remember();
+        self.selected_range = Some(pending_range);
+        Some(pending_range)
+    }
+
+    /// replaces the selection without storing it in the undo history, e.g. for the initial selection
+    pub fn set(&mut self, date: NaiveDate, range_selection: bool) {
+        if range_selection {
+            self.selected_range = Some(DateRange::new(date, date));
+        } else {
+            self.selected_date = Some(date);
+        }
+    }
+
+    /// clears the selected date, e.g. when it gets forbidden by new constraints
+    pub fn clear(&mut self) {
+        self.selected_date = None;
+    }
+
+    /// drops the dates and the ranges the changed `config` forbids, returns whether the selected date
+    /// or the selected range was among them
+    pub fn drop_forbidden<T: HasDateConstraints + Default + Clone>(
+        &mut self,
+        config: &PickerConfig<T>,
+        options: &PickerOptions,
+    ) -> bool {
+        let is_range_forbidden = |range: &DateRange| {
+            config.is_day_forbidden(&range.start())
+                || config.is_day_forbidden(&range.end())
+                || (!*options.allow_forbidden_in_range()
+                    && contains_forbidden_day(range.start(), range.end(), config))
+        };
+        self.pending_date = self
+            .pending_date
+            .filter(|pending_date| !config.is_day_forbidden(pending_date));
+        self.range_start = self
+            .range_start
+            .filter(|range_start| !config.is_day_forbidden(range_start));
+        self.pending_range = self
+            .pending_range
+            .filter(|pending_range| !is_range_forbidden(pending_range));
+        let date_forbidden = self
+            .selected_date
+            .is_some_and(|selected_date| config.is_day_forbidden(&selected_date));
+        if date_forbidden {
+            self.clear();
+        }
+        let range_forbidden = self.selected_range.as_ref().is_some_and(is_range_forbidden);
+        if range_forbidden {
+            self.selected_range = None;
+        }
+        date_forbidden || range_forbidden
+    }
+
+    /// drops the pending date, the pending range and the started range, e.g. when the dialog is closed
+    pub fn discard_unfinished(&mut self) {
+        self.pending_date = None;
+        self.pending_range = None;
+        self.range_start = None;
+    }
+
+    /// whether the `date` is too close to or too far from the `range_start` to be the other end of the range,
+    /// or whether the range between them would contain a forbidden day when that is not allowed
+    pub fn is_outside_range_window<T: HasDateConstraints + Default + Clone>(
+        &self,
+        date: &NaiveDate,
+        config: &PickerConfig<T>,
+        options: &PickerOptions,
+    ) -> bool {
+        let range_start = match self.range_start {
+            Some(range_start) => range_start,
+            None => return false,
+        };
+        let length = (*date - range_start).num_days().abs();
+        options
+            .min_range_length()
+            .is_some_and(|min_length| length < i64::from(min_length))
+            || options
+                .max_range_length()
+                .is_some_and(|max_length| length > i64::from(max_length))
+            || (!*options.allow_forbidden_in_range()
+                && contains_forbidden_day(range_start, *date, config))
+    }
+
+    /// restores the previous selection, returns whether there was one
+    pub fn undo(&mut self) -> bool {
+        match self.undo_history.pop() {
+            Some(selection) => {
+                self.redo_history.push(self.selection());
+                self.restore(selection);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// restores the selection undone by `undo`, returns whether there was one
+    pub fn redo(&mut self) -> bool {
+        match self.redo_history.pop() {
+            Some(selection) => {
+                self.undo_history.push(self.selection());
+                self.restore(selection);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// stores the current selection before it changes, the redo history is discarded
+    fn remember(&mut self) {
+        if self.undo_history.len() == SELECTION_HISTORY_LENGTH {
+            self.undo_history.remove(0);
+        }
+        self.undo_history.push(self.selection());
+        self.redo_history.clear();
+    }
+
+    fn restore(&mut self, (selected_date, selected_range): Selection) {
+        self.selected_date = selected_date;
+        self.selected_range = selected_range;
+        self.discard_unfinished();
+    }
+}
+
+/// `ViewState` describes the page displayed by the dialog independently of its view.
+///
+/// The pages depend on the `PickerConfig` and the `PickerOptions`, e.g. on the week strip or the fiscal years,
+/// which are passed to its methods, e.g. the Seed `Model` delegates its navigation here.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ViewState {
+    /// date that determines the displayed year group, year or month
+    pub(crate) viewed_date: NaiveDate,
+
+    /// displayed periods
+    pub(crate) dialog_view_type: DialogViewType,
+}
+
+/// `ViewMsg` describes the transitions of the `ViewState`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ViewMsg {
+    /// the previous page, if it has a selectable date or is within the `navigation_min` option
+    Previous,
+    /// the next page, if it has a selectable date or is within the `navigation_max` option
+    Next,
+    /// moves the viewed date by the months and years of the calendar, within the `navigation_min`
+    /// and `navigation_max` options
+    Shift { months: i32, years: i32 },
+    /// views the given date
+    Navigate(NaiveDate),
+    /// displays the larger periods, e.g. the months instead of the days
+    ZoomOut,
+    /// displays the smaller periods, down to the `selection_type` of the config
+    ZoomIn,
+    /// displays the given periods, unless they are smaller than the `selection_type` of the config
+    SetViewType(DialogViewType),
+    /// the month with the given number was clicked in the months view
+    MonthSelected(MonthNumber),
+    /// the year was clicked in the years view
+    YearSelected(YearNumber),
+}
+
+/// `ViewChange` is the outcome of a `ViewMsg`, so that the renderer can e.g. move the keyboard focus.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ViewChange {
+    /// nothing changed, e.g. the previous page has nothing selectable
+    Unchanged,
+    /// the viewed date changed
+    Navigated,
+    /// the `DialogViewType` changed
+    Zoomed,
+    /// the month or the year of the `selection_type` was clicked, the renderer selects the date
+    Selected(NaiveDate),
+}
+
+/// `PageCell` describes a cell of the displayed page, so that every renderer displays the same cells.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageCell {
+    /// the day, or the first day of the month or the year of the cell
+    pub date: NaiveDate,
+    /// content of the cell, according to the label options
+    pub label: String,
+    /// whether nothing can be selected in the cell
+    pub is_forbidden: bool,
+    /// whether the cell contains the displayed selection
+    pub is_selected: bool,
+    /// whether the cell contains today, `false` if the `highlight_today` option is off
+    pub is_today: bool,
+    /// whether the day is outside of the viewed month
+    pub is_other_month: bool,
+    /// whether the day outside of the viewed month is hidden by the `show_other_month_days` option,
+    /// it is rendered as an empty cell
+    pub is_empty: bool,
+    /// transition of a click on the month or the year, `None` for a day, which gets selected
+    pub view_msg: Option<ViewMsg>,
+}
+
+impl ViewState {
+    /// page displayed when the picker is created, see `initial_view_type` and `initial_date` of the `config`
+    pub fn new<T: HasDateConstraints + Default + Clone>(
+        config: &PickerConfig<T>,
+        options: &PickerOptions,
+    ) -> Self {
+        Self {
+            viewed_date: initial_viewed_date(config, options.today()),
+            dialog_view_type: *config.initial_view_type(),
+        }
+    }
+
+    /// date that determines the displayed year group, year or month
+    pub fn viewed_date(&self) -> &NaiveDate {
+        &self.viewed_date
+    }
+
+    /// displayed periods
+    pub fn dialog_view_type(&self) -> &DialogViewType {
+        &self.dialog_view_type
+    }
+
+    /// title of the displayed page, see `format::format_title`
+    pub fn title<T: HasDateConstraints + Default + Clone>(
+        &self,
+        config: &PickerConfig<T>,
+        options: &PickerOptions,
+    ) -> String {
+        format_title(&self.dialog_view_type, &self.viewed_date, config, options)
+    }
+
+    /// applies the `msg`, returns what changed
+    pub fn update<T: HasDateConstraints + Default + Clone>(
+        &mut self,
+        msg: ViewMsg,
+        config: &PickerConfig<T>,
+        options: &PickerOptions,
+    ) -> ViewChange {
+        let old = *self;
+        match msg {
+            ViewMsg::Previous | ViewMsg::Next => {
+                let forward = msg == ViewMsg::Next;
+                if self.can_navigate(forward, config, options) {
+                    self.viewed_date = self.adjacent_viewed_date(forward, options);
+                }
+            }
+            ViewMsg::Shift { months, years } => {
+                self.viewed_date = self.shifted_viewed_date(months, years, options)
+            }
+            ViewMsg::Navigate(date) => self.viewed_date = date,
+            ViewMsg::ZoomOut => {
+                if let Some(larger) = self.dialog_view_type.larger_type() {
+                    self.dialog_view_type = larger;
+                }
+            }
+            ViewMsg::ZoomIn => {
+                if let Some(smaller) = smaller_view_type(&self.dialog_view_type).filter(|smaller| {
+                    view_type_rank(smaller) <= view_type_rank(config.selection_type())
+                }) {
+                    self.dialog_view_type = smaller;
+                }
+            }
+            ViewMsg::SetViewType(dialog_view_type) => {
+                if view_type_rank(&dialog_view_type) <= view_type_rank(config.selection_type()) {
+                    self.dialog_view_type = dialog_view_type;
+                }
+            }
+            ViewMsg::MonthSelected(month) => {
+                let month = match self.displayed_month(month, options) {
+                    Some(month) => month,
+                    None => return ViewChange::Unchanged,
+                };
+                if config.selection_type() == &DialogViewType::Months {
+                    self.viewed_date = month;
+                    return ViewChange::Selected(month);
+                }
+                let calendar = calendar(options);
+                self.viewed_date = if *options.preserve_viewed_date() {
+                    calendar.with_day_clamped(&month, calendar.calendar_date(&self.viewed_date).day)
+                } else {
+                    month
+                };
+                self.dialog_view_type = DialogViewType::Days;
+            }
+            ViewMsg::YearSelected(year) => {
+                if config.selection_type() == &DialogViewType::Years {
+                    self.viewed_date = ymd_clamped(year, 1, 1);
+                    return ViewChange::Selected(self.viewed_date);
+                }
+                self.viewed_date = if *options.preserve_viewed_date() {
+                    self.viewed_date.add_years(year - self.viewed_date.year())
+                } else {
+                    ymd_clamped(year, 1, 1)
+                };
+                self.dialog_view_type = DialogViewType::Months;
+            }
+        }
+        if self.dialog_view_type != old.dialog_view_type {
+            ViewChange::Zoomed
+        } else if self.viewed_date != old.viewed_date {
+            ViewChange::Navigated
+        } else {
+            ViewChange::Unchanged
+        }
+    }
+
+    /// whether `ViewMsg::Previous` changes the page
+    pub fn can_navigate_previous<T: HasDateConstraints + Default + Clone>(
+        &self,
+        config: &PickerConfig<T>,
+        options: &PickerOptions,
+    ) -> bool {
+        self.can_navigate(false, config, options)
+    }
+
+    /// whether `ViewMsg::Next` changes the page
+    pub fn can_navigate_next<T: HasDateConstraints + Default + Clone>(
+        &self,
+        config: &PickerConfig<T>,
+        options: &PickerOptions,
+    ) -> bool {
+        self.can_navigate(true, config, options)
+    }
+
+    /// cells of the displayed page with the displayed `selection`, the days outside of the window
+    /// of the range being picked are forbidden, the agenda layout of the days is displayed as the grid
+    pub fn cells<T: HasDateConstraints + Default + Clone>(
+        &self,
+        config: &PickerConfig<T>,
+        options: &PickerOptions,
+        selection: &SelectionState,
+    ) -> Vec<PageCell> {
+        let selected = selection.displayed();
+        let calendar = calendar(options);
+        let today = Some(options.today()).filter(|_| *options.highlight_today());
+        let is_same_month = |a: &NaiveDate, b: &NaiveDate| {
+            let (a, b) = (calendar.calendar_date(a), calendar.calendar_date(b));
+            a.year == b.year && a.month == b.month
+        };
+        match self.dialog_view_type {
+            DialogViewType::Days => {
+                let first_day_of_month = calendar.first_day_of_month(&self.viewed_date);
+                let (first_day, last_day) = if self.is_week_strip(options) {
+                    self.page_bounds(&self.viewed_date, options)
+                } else {
+                    let last_day_of_month = calendar
+                        .month_offset(&first_day_of_month, 1)
+                        .pred_opt()
+                        .unwrap_or(NaiveDate::MAX);
+                    let first_day = week_start(&first_day_of_month);
+                    let weeks = if *options.trim_trailing_weeks() {
+                        (last_day_of_month - first_day).num_days() / 7 + 1
+                    } else {
+                        6
+                    };
+                    let last_day = first_day
+                        .checked_add_signed(Duration::days(7 * weeks - 1))
+                        .unwrap_or(NaiveDate::MAX);
+                    (first_day, last_day)
+                };
+                first_day
+                    .iter_days()
+                    .take_while(|day| *day <= last_day)
+                    .map(|day| {
+                        let is_other_month = !self.is_week_strip(options)
+                            && !is_same_month(&day, &first_day_of_month);
+                        PageCell {
+                            date: day,
+                            label: day_label(&day, options),
+                            is_forbidden: config.is_day_forbidden(&day)
+                                || selection.is_outside_range_window(&day, config, options),
+                            is_selected: selected == Some(day),
+                            is_today: today == Some(day),
+                            is_other_month,
+                            is_empty: is_other_month && !*options.show_other_month_days(),
+                            view_msg: None,
+                        }
+                    })
+                    .collect()
+            }
+            DialogViewType::Months => displayed_months(&self.viewed_date, options)
+                .into_iter()
+                .map(|month| PageCell {
+                    date: month,
+                    label: month_label(&month, options),
+                    is_forbidden: is_month_forbidden(&month, config, options),
+                    is_selected: selected.is_some_and(|selected| is_same_month(&selected, &month)),
+                    is_today: today.is_some_and(|today| is_same_month(&today, &month)),
+                    is_other_month: false,
+                    is_empty: false,
+                    view_msg: Some(ViewMsg::MonthSelected(calendar.calendar_date(&month).month)),
+                })
+                .collect(),
+            DialogViewType::Years => {
+                let representable_years = NaiveDate::MIN.year()..=NaiveDate::MAX.year();
+                year_group_range(self.viewed_date.year())
+                    .filter(|year| representable_years.contains(year))
+                    .map(|year| PageCell {
+                        date: ymd_clamped(year, 1, 1),
+                        label: display_year(year, options),
+                        is_forbidden: config.is_year_forbidden(year),
+                        is_selected: selected.is_some_and(|selected| selected.year() == year),
+                        is_today: today.is_some_and(|today| today.year() == year),
+                        is_other_month: false,
+                        is_empty: false,
+                        view_msg: Some(ViewMsg::YearSelected(year)),
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// number of the columns of the grid of the `cells`
+    pub fn grid_columns(&self, options: &PickerOptions) -> usize {
+        match self.dialog_view_type {
+            DialogViewType::Days => 7,
+            DialogViewType::Months => usize::from(*options.month_grid_columns()),
+            DialogViewType::Years => usize::from(*options.year_grid_columns()),
+        }
+    }
+
+    /// whether the next page if `forward`, otherwise the previous one, can be displayed,
+    /// limited by the `navigation_min` and `navigation_max` options if there are any
+    fn can_navigate<T: HasDateConstraints + Default + Clone>(
+        &self,
+        forward: bool,
+        config: &PickerConfig<T>,
+        options: &PickerOptions,
+    ) -> bool {
+        let (first_day, last_day) = self.page_bounds(&self.viewed_date, options);
+        match (forward, options.navigation_min(), options.navigation_max()) {
+            (false, Some(navigation_min), _) => first_day > *navigation_min,
+            (true, _, Some(navigation_max)) => last_day < *navigation_max,
+            _ if self.is_custom_page(options) => self.has_allowed_day(
+                &self.adjacent_viewed_date(forward, options),
+                config,
+                options,
+            ),
+            _ => self.is_page_allowed(&self.adjacent_viewed_date(forward, options), config),
+        }
+    }
+
+    /// viewed date of the previous or the next page of the dialog
+    fn adjacent_viewed_date(&self, forward: bool, options: &PickerOptions) -> NaiveDate {
+        let offset = if forward { 1 } else { -1 };
+        let is_custom_calendar = options.calendar_system().is_some();
+        match self.dialog_view_type {
+            DialogViewType::Days if self.is_week_strip(options) => {
+                self.viewed_date.add_weeks(offset)
+            }
+            DialogViewType::Days if is_custom_calendar => {
+                calendar(options).month_offset(&self.viewed_date, offset)
+            }
+            DialogViewType::Months if is_custom_calendar => {
+                calendar(options).year_offset(&self.viewed_date, offset)
+            }
+            DialogViewType::Days => {
+                let month = self.viewed_date.add_months(offset);
+                ymd_clamped(month.year(), month.month(), 1)
+            }
+            DialogViewType::Months => ymd_clamped(self.viewed_date.add_years(offset).year(), 1, 1),
+            DialogViewType::Years => {
+                ymd_clamped(self.viewed_date.add_year_groups(offset).year(), 1, 1)
+            }
+        }
+    }
+
+    /// viewed date moved by the given number of months and years of the calendar, the day is clamped
+    /// to the length of the target month and the result to the `navigation_min` and `navigation_max` options
+    fn shifted_viewed_date(&self, months: i32, years: i32, options: &PickerOptions) -> NaiveDate {
+        let shifted = if options.calendar_system().is_some() {
+            let calendar = calendar(options);
+            let viewed = calendar.calendar_date(&self.viewed_date);
+            let year = viewed.year.saturating_add(years);
+            let month_start = calendar
+                .to_naive_date(&CalendarDate {
+                    year,
+                    month: viewed.month.min(calendar.month_count(year)),
+                    day: 1,
+                })
+                .unwrap_or(saturated_date(years));
+            calendar.with_day_clamped(&calendar.month_offset(&month_start, months), viewed.day)
+        } else {
+            self.viewed_date.add_years(years).add_months(months)
+        };
+        let shifted = options
+            .navigation_min()
+            .map_or(shifted, |navigation_min| shifted.max(navigation_min));
+        options
+            .navigation_max()
+            .map_or(shifted, |navigation_max| shifted.min(navigation_max))
+    }
+
+    /// first and last day of the period displayed by the dialog viewing the `viewed_date`
+    pub(crate) fn page_bounds(
+        &self,
+        viewed_date: &NaiveDate,
+        options: &PickerOptions,
+    ) -> (NaiveDate, NaiveDate) {
+        let calendar = calendar(options);
+        let is_custom_calendar = options.calendar_system().is_some();
+        match self.dialog_view_type {
+            DialogViewType::Days if self.is_week_strip(options) => {
+                let week_start = week_start(viewed_date);
+                (
+                    week_start,
+                    week_start
+                        .checked_add_signed(Duration::days(6))
+                        .unwrap_or(NaiveDate::MAX),
+                )
+            }
+            DialogViewType::Days if is_custom_calendar => (
+                calendar.first_day_of_month(viewed_date),
+                calendar
+                    .month_offset(viewed_date, 1)
+                    .pred_opt()
+                    .unwrap_or(NaiveDate::MAX),
+            ),
+            DialogViewType::Months if is_custom_calendar => (
+                calendar.year_offset(viewed_date, 0),
+                calendar
+                    .year_offset(viewed_date, 1)
+                    .pred_opt()
+                    .unwrap_or(NaiveDate::MAX),
+            ),
+            _ => page_bounds(
+                &self.dialog_view_type,
+                viewed_date,
+                *options.fiscal_year_start(),
+            ),
+        }
+    }
+
+    /// whether the month, the year or the year group viewing the `viewed_date` isn't entirely forbidden,
+    /// the year groups are aligned by the `year_group_range` of this crate
+    fn is_page_allowed<T: HasDateConstraints + Default + Clone>(
+        &self,
+        viewed_date: &NaiveDate,
+        config: &PickerConfig<T>,
+    ) -> bool {
+        match self.dialog_view_type {
+            DialogViewType::Days => !config.is_month_forbidden(viewed_date),
+            DialogViewType::Months => !config.is_year_forbidden(viewed_date.year()),
+            DialogViewType::Years => !config.is_year_group_forbidden(viewed_date.year()),
+        }
+    }
+
+    /// whether the page of the dialog viewing the `viewed_date` contains a selectable day
+    fn has_allowed_day<T: HasDateConstraints + Default + Clone>(
+        &self,
+        viewed_date: &NaiveDate,
+        config: &PickerConfig<T>,
+        options: &PickerOptions,
+    ) -> bool {
+        match self.dialog_view_type {
+            DialogViewType::Months => displayed_months(viewed_date, options)
+                .iter()
+                .any(|month| !is_month_forbidden(month, config, options)),
+            _ => {
+                let (first_day, last_day) = self.page_bounds(viewed_date, options);
+                first_day
+                    .iter_days()
+                    .take_while(|day| *day <= last_day)
+                    .any(|day| !config.is_day_forbidden(&day))
+            }
+        }
+    }
+
+    /// whether the displayed page is not a Gregorian month or year group,
+    /// which the navigation functions of chrono-datepicker-core expect
+    fn is_custom_page(&self, options: &PickerOptions) -> bool {
+        let is_custom_calendar = options.calendar_system().is_some();
+        match self.dialog_view_type {
+            DialogViewType::Years => false,
+            DialogViewType::Months => self.is_fiscal_year_displayed(options) || is_custom_calendar,
+            DialogViewType::Days => is_custom_calendar || self.is_week_strip(options),
+        }
+    }
+
+    /// whether the days view displays a single week, see `DaysLayout::WeekStrip`
+    pub(crate) fn is_week_strip(&self, options: &PickerOptions) -> bool {
+        self.dialog_view_type == DialogViewType::Days
+            && *options.days_layout() == DaysLayout::WeekStrip
+    }
+
+    /// whether the dialog displays the months of a fiscal year instead of a calendar year,
+    /// fiscal years are only supported in the Gregorian calendar
+    fn is_fiscal_year_displayed(&self, options: &PickerOptions) -> bool {
+        self.dialog_view_type == DialogViewType::Months
+            && options.fiscal_year_start().is_some()
+            && options.calendar_system().is_none()
+    }
+
+    /// first day of the displayed month with the given number, the year depends on the fiscal year,
+    /// `None` if there is no such month, e.g. in a `ViewMsg::MonthSelected` sent by the app
+    fn displayed_month(&self, month: MonthNumber, options: &PickerOptions) -> Option<NaiveDate> {
+        displayed_months(&self.viewed_date, options)
+            .into_iter()
+            .find(|displayed_month| calendar(options).calendar_date(displayed_month).month == month)
+    }
+}
+
+/// first days of the months displayed in the `DialogViewType::Months`, a calendar or a fiscal year
+pub(crate) fn displayed_months(viewed_date: &NaiveDate, options: &PickerOptions) -> Vec<NaiveDate> {
+    let calendar = calendar(options);
+    let first_month = match options.fiscal_year_start() {
+        Some(fiscal_year_start) if options.calendar_system().is_none() => {
+            fiscal_year_first_month(viewed_date, *fiscal_year_start)
+        }
+        _ => calendar.year_offset(viewed_date, 0),
+    };
+    let month_count = calendar.month_count(calendar.calendar_date(&first_month).year);
+    (0..month_count as i32)
+        .map(|months| calendar.month_offset(&first_month, months))
+        .collect()
+}
+
+/// whether any day strictly between the two dates is forbidden by the constraints
+fn contains_forbidden_day<T: HasDateConstraints + Default + Clone>(
+    a: NaiveDate,
+    b: NaiveDate,
+    config: &PickerConfig<T>,
+) -> bool {
+    let range = DateRange::new(a, b);
+    range
+        .iter_days()
+        .filter(|day| *day != range.start() && *day != range.end())
+        .any(|day| config.is_day_forbidden(&day))
+}
+
+/// whether the month of the calendar system containing the `date` has no selectable day
+pub(crate) fn is_month_forbidden<T: HasDateConstraints + Default + Clone>(
+    date: &NaiveDate,
+    config: &PickerConfig<T>,
+    options: &PickerOptions,
+) -> bool {
+    if options.calendar_system().is_none() {
+        return config.is_month_forbidden(date);
+    }
+    let calendar = calendar(options);
+    let last_day = calendar
+        .month_offset(date, 1)
+        .pred_opt()
+        .unwrap_or(NaiveDate::MAX);
+    calendar
+        .first_day_of_month(date)
+        .iter_days()
+        .take_while(|day| *day <= last_day)
+        .all(|day| config.is_day_forbidden(&day))
+}
+
+/// headers of the columns of the days view, starting on Monday, according to the `weekday_label_format` option
+pub fn weekday_labels(options: &PickerOptions) -> Vec<String> {
+    [
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ]
+    .iter()
+    .map(|day| options.weekday_label_format().label(*day))
+    .collect()
+}
@@ -0,0 +1,105 @@
+//! Many datepickers on one page, identified by the keys of the app, e.g. the fields of a form.
+//!
+//! The messages of all the pickers are routed as `(K, Msg)` tuples and only one dialog is open at a time.
+use std::{collections::HashMap, hash::Hash};
+
+use chrono::NaiveDate;
+use chrono_datepicker_core::config::{date_constraints::HasDateConstraints, PickerConfig};
+use seed::prelude::*;
+
+use crate::{config::date_constraints::DateConstraints, options::PickerOptions, Model, Msg};
+
+/// `PickerSet` describes the state of the datepickers identified by the keys `K`.
+pub struct PickerSet<K, T: HasDateConstraints + Default + Clone = DateConstraints> {
+    pickers: HashMap<K, Model<T>>,
+}
+
+impl<K, T: HasDateConstraints + Default + Clone> Default for PickerSet<K, T> {
+    fn default() -> Self {
+        Self {
+            pickers: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone + 'static, T: HasDateConstraints + Default + Clone> PickerSet<K, T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// initializes a picker under the `key`, replacing the previous one
+    #[allow(clippy::too_many_arguments)]
+    pub fn init<Ms: 'static>(
+        &mut self,
+        key: K,
+        url: Url,
+        orders: &mut impl Orders<Ms>,
+        config: PickerConfig<T>,
+        date_constraints: T,
+        options: PickerOptions,
+        to_msg: impl FnOnce((K, Msg)) -> Ms + Clone + 'static,
+    ) {
+        let model = crate::init_with_options(url, orders, config, date_constraints, options, {
+            let key = key.clone();
+            move |msg| to_msg((key, msg))
+        });
+        self.pickers.insert(key, model);
+    }
+
+    pub fn get(&self, key: &K) -> Option<&Model<T>> {
+        self.pickers.get(key)
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut Model<T>> {
+        self.pickers.get_mut(key)
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<Model<T>> {
+        self.pickers.remove(key)
+    }
+
+    /// routes the `msg` to the picker of the `key`, opening a dialog closes the other ones,
+    /// `on_change` is sent when the selected date of the picker changes
+    pub fn update<Ms: 'static>(
+        &mut self,
+        (key, msg): (K, Msg),
+        orders: &mut impl Orders<Ms>,
+        on_change: impl FnOnce(K, Option<NaiveDate>) -> Ms,
+        to_msg: impl FnOnce((K, Msg)) -> Ms + Clone + 'static,
+    ) {
+        if let Msg::OpenDialog(_) | Msg::OpenDialogAt(_) = msg {
+            for (other_key, other) in self.pickers.iter_mut() {
+                if *other_key != key && other.is_open() {
+                    crate::update_model(Msg::CloseDialog, other, orders, {
+                        let (to_msg, other_key) = (to_msg.clone(), other_key.clone());
+                        move |msg| to_msg((other_key, msg))
+                    });
+                }
+            }
+        }
+        if let Some(model) = self.pickers.get_mut(&key) {
+            let new_selection = crate::update_model(msg, model, orders, {
+                let key = key.clone();
+                move |msg| to_msg((key, msg))
+            });
+            if let Some(new_selection) = new_selection {
+                orders.send_msg(on_change(key, new_selection));
+            }
+        }
+    }
+
+    /// view of the picker of the `key`, empty if there is none
+    pub fn view<Ms: 'static>(
+        &self,
+        key: &K,
+        to_msg: impl FnOnce((K, Msg)) -> Ms + Clone + 'static,
+    ) -> Node<Ms> {
+        match self.pickers.get(key) {
+            Some(model) => crate::view(model, {
+                let key = key.clone();
+                move |msg| to_msg((key, msg))
+            }),
+            None => Node::Empty,
+        }
+    }
+}
@@ -0,0 +1,140 @@
+//! Keyboard shortcuts of the dialog, the key presses inside of the dialog are mapped to `KeyAction`s
+//! by the `KeyBindings` of the `PickerOptions`.
+use std::collections::HashMap;
+
+/// `KeyAction` triggered by a key combination.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyAction {
+    /// next month in the days view, next page in the other views
+    NextMonth,
+    /// previous month in the days view, previous page in the other views
+    PreviousMonth,
+    /// selects the current date, if it is allowed
+    SelectToday,
+    Close,
+    /// switches to the larger `DialogViewType`, like clicking the title
+    ZoomOut,
+    NextYear,
+    PreviousYear,
+    /// appends the pressed digit to the typed day number
+    TypeDigit,
+    /// selects the typed day number in the viewed month, if it is allowed
+    SelectTypedDay,
+}
+
+/// `KeyCombination` of a key, as in `KeyboardEvent.key`, and the modifiers.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeyCombination {
+    pub key: String,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+}
+
+impl KeyCombination {
+    /// the `key` without any modifiers, e.g. `"PageDown"`
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            ctrl: false,
+            alt: false,
+            shift: false,
+        }
+    }
+
+    pub fn ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    pub fn alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+
+    pub fn shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    pub(crate) fn from_event(event: &web_sys::KeyboardEvent) -> Self {
+        Self {
+            key: event.key(),
+            ctrl: event.ctrl_key(),
+            alt: event.alt_key(),
+            shift: event.shift_key(),
+        }
+    }
+}
+
+/// `KeyBindings` map the key combinations to the `KeyAction`s,
+/// by default `PageUp`/`PageDown` navigate and `Escape` closes the dialog.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyBindings {
+    bindings: HashMap<KeyCombination, KeyAction>,
+}
+
+impl KeyBindings {
+    /// no shortcuts at all
+    pub fn empty() -> Self {
+        Self {
+            bindings: HashMap::new(),
+        }
+    }
+
+    /// binds the `combination` to the `action`, replacing its previous action
+    pub fn bind(&mut self, combination: KeyCombination, action: KeyAction) -> &mut Self {
+        self.bindings.insert(combination, action);
+        self
+    }
+
+    pub fn unbind(&mut self, combination: &KeyCombination) -> &mut Self {
+        self.bindings.remove(combination);
+        self
+    }
+
+    /// action of the `combination`, a single character like `{` is also found among the bindings
+    /// without the `shift`, which some keyboard layouts need to type it
+    pub fn action(&self, combination: &KeyCombination) -> Option<KeyAction> {
+        self.bindings.get(combination).copied().or_else(|| {
+            if !combination.shift || combination.key.chars().count() != 1 {
+                return None;
+            }
+            let unshifted = KeyCombination {
+                shift: false,
+                ..combination.clone()
+            };
+            self.bindings.get(&unshifted).copied()
+        })
+    }
+}
+
+impl KeyBindings {
+    /// the default bindings extended with Vim-like keys: `t` for today, `[`/`]` for the previous/next month,
+    /// `{`/`}` for the previous/next year and a day number followed by `Enter` to select that day
+    pub fn vim() -> Self {
+        let mut key_bindings = Self::default();
+        key_bindings
+            .bind(KeyCombination::new("t"), KeyAction::SelectToday)
+            .bind(KeyCombination::new("["), KeyAction::PreviousMonth)
+            .bind(KeyCombination::new("]"), KeyAction::NextMonth)
+            .bind(KeyCombination::new("{"), KeyAction::PreviousYear)
+            .bind(KeyCombination::new("}"), KeyAction::NextYear)
+            .bind(KeyCombination::new("Enter"), KeyAction::SelectTypedDay);
+        for digit in 0..=9 {
+            key_bindings.bind(KeyCombination::new(digit.to_string()), KeyAction::TypeDigit);
+        }
+        key_bindings
+    }
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        let mut key_bindings = Self::empty();
+        key_bindings
+            .bind(KeyCombination::new("PageUp"), KeyAction::PreviousMonth)
+            .bind(KeyCombination::new("PageDown"), KeyAction::NextMonth)
+            .bind(KeyCombination::new("Escape"), KeyAction::Close);
+        key_bindings
+    }
+}
@@ -0,0 +1,185 @@
+//! `DatePicker` component for the Leptos apps, enabled by the `leptos` feature.
+//!
+//! The selected date is bound to a signal of the app, the selection and the displayed page are kept
+//! in the signals of the component, driven by the renderer-agnostic `headless::SelectionState`
+//! and `headless::ViewState` and checked against the same `PickerConfig`, `PickerOptions`
+//! and date constraints as the Seed pickers, so it zooms out to the months and the years like the Seed view:
+//!
+//! ```rust,ignore
+//! let selected = create_rw_signal(None);
+//! view! {
+//!     <DatePicker config=config.clone() options=options.clone() selected=selected on_close=move |_| set_opened(false)/>
+//!     <p>{move || format!("Selected date: {:?}", selected.get())}</p>
+//! }
+//! ```
+use std::rc::Rc;
+
+use chrono::NaiveDate;
+use chrono_datepicker_core::{
+    config::{date_constraints::HasDateConstraints, PickerConfig},
+    dialog_view_type::DialogViewType,
+};
+use leptos::*;
+
+use crate::{
+    headless::{weekday_labels, SelectionState, ViewChange, ViewMsg, ViewState},
+    options::PickerOptions,
+    style_names::*,
+};
+
+/// Leptos counterpart of the Seed `view`, the `selected` signal gets the newly selected date
+/// and its initial value takes precedence over the initial date of the `config`
+#[component]
+pub fn DatePicker<T: HasDateConstraints + Default + Clone + 'static>(
+    config: Rc<PickerConfig<T>>,
+    selected: RwSignal<Option<NaiveDate>>,
+    /// the same options as passed to the Seed `init_with_options`
+    #[prop(optional)]
+    options: Rc<PickerOptions>,
+    /// called when the close button is clicked or a date gets selected
+    #[prop(optional, into)]
+    on_close: Option<Callback<()>>,
+) -> impl IntoView {
+    let initial_date = selected.get_untracked().or(*config.initial_date());
+    let selection = create_rw_signal(SelectionState::new(initial_date));
+    let view_state = create_rw_signal(ViewState::new(&*config, &*options));
+    let close = move || {
+        if let Some(on_close) = on_close {
+            on_close.call(());
+        }
+    };
+    let select = move |date: NaiveDate| {
+        selection.update(|selection| selection.select(date));
+        selected.set(Some(date));
+        close();
+    };
+    // a month or a year of the `selection_type` clicked in the months or the years view gets selected
+    let update_view = {
+        let (config, options) = (config.clone(), options.clone());
+        Rc::new(move |msg: ViewMsg| {
+            let mut change = ViewChange::Unchanged;
+            view_state.update(|view_state| change = view_state.update(msg, &*config, &*options));
+            if let ViewChange::Selected(date) = change {
+                select(date);
+            }
+        })
+    };
+
+    let navigation_button = |class_name: &'static str, label: &'static str, msg: ViewMsg| {
+        let (config, options) = (config.clone(), options.clone());
+        let update_view = update_view.clone();
+        let available = move || {
+            view_state.with(|view_state| {
+                if msg == ViewMsg::Next {
+                    view_state.can_navigate_next(&*config, &*options)
+                } else {
+                    view_state.can_navigate_previous(&*config, &*options)
+                }
+            })
+        };
+        view! {
+            <button
+                class=format!("{} {}", BUTTON, class_name)
+                disabled=move || !available()
+                on:click=move |_| update_view(msg)
+            >
+                {label}
+            </button>
+        }
+    };
+
+    let cells = {
+        let (config, options) = (config.clone(), options.clone());
+        let update_view = update_view.clone();
+        move || {
+            selection
+                .with(|selection| {
+                    view_state.with(|view_state| view_state.cells(&*config, &*options, selection))
+                })
+                .into_iter()
+                .map(|cell| {
+                    if cell.is_empty {
+                        return view! { <span class=EMPTY_CELL role="gridcell"></span> };
+                    }
+                    let mut classes = vec![if cell.is_forbidden {
+                        UNAVAILABLE
+                    } else {
+                        SELECTABLE
+                    }];
+                    classes.extend(cell.is_other_month.then_some(OTHER_MONTH));
+                    classes.extend(cell.is_selected.then_some(SELECTED));
+                    classes.extend(cell.is_today.then_some(TODAY));
+                    let update_view = update_view.clone();
+                    let (date, view_msg, is_forbidden) =
+                        (cell.date, cell.view_msg, cell.is_forbidden);
+                    view! {
+                        <span
+                            class=classes.join(" ")
+                            role="gridcell"
+                            aria-selected=cell.is_selected.to_string()
+                            data-date=date.format("%Y-%m-%d").to_string()
+                            on:click=move |_| {
+                                if !is_forbidden {
+                                    match view_msg {
+                                        Some(msg) => update_view(msg),
+                                        None => {
+                                            update_view(ViewMsg::Navigate(date));
+                                            select(date);
+                                        }
+                                    }
+                                }
+                            }
+                        >
+                            {cell.label}
+                        </span>
+                    }
+                })
+                .collect_view()
+        }
+    };
+    let weekday_names = {
+        let options = options.clone();
+        move || {
+            (view_state.with(|view_state| view_state.dialog_view_type() == &DialogViewType::Days))
+                .then(|| weekday_labels(&options))
+                .unwrap_or_default()
+                .into_iter()
+                .map(|label| view! { <span class=GRID_HEADER role="columnheader">{label}</span> })
+                .collect_view()
+        }
+    };
+    let title = {
+        let (config, options) = (config.clone(), options.clone());
+        move || view_state.with(|view_state| view_state.title(&*config, &*options))
+    };
+    let grid_columns = {
+        let options = options.clone();
+        move || {
+            let columns = view_state.with(|view_state| view_state.grid_columns(&options));
+            format!("grid-template-columns: {}", "1fr ".repeat(columns))
+        }
+    };
+    let zoom_out = {
+        let update_view = update_view.clone();
+        move |_: ev::MouseEvent| update_view(ViewMsg::ZoomOut)
+    };
+
+    view! {
+        <div class=DATEPICKER_ROOT>
+            <div class=HEADER>
+                {navigation_button(PREVIOUS, "«", ViewMsg::Previous)}
+                <span class=TITLE role="heading" on:click=zoom_out>
+                    {title}
+                </span>
+                {navigation_button(NEXT, "»", ViewMsg::Next)}
+                <button class=format!("{} {}", BUTTON, CLOSE) on:click=move |_| close()>
+                    "x"
+                </button>
+            </div>
+            <div class=BODY style=grid_columns>
+                {weekday_names}
+                {cells}
+            </div>
+        </div>
+    }
+}
@@ -2,17 +2,67 @@
 
 use chrono::{prelude::*, Duration};
 use chrono_datepicker_core::{
-    config::{date_constraints::HasDateConstraints, PickerConfig},
+    config::{date_constraints::HasDateConstraints, PickerConfig, PickerConfigBuilder},
     dialog_view_type::DialogViewType,
-    style_names::*,
-    utils::{create_dialog_title_text, should_display_next_button, should_display_previous_button},
-    viewed_date::{year_group_range, MonthNumber, ViewedDate, YearNumber},
+    viewed_date::{MonthNumber, YearNumber},
 };
-use num_traits::FromPrimitive;
 use seed::{prelude::*, *};
+use std::{convert::TryFrom, fmt};
+
+pub mod calendar;
+mod color_scheme;
+pub mod component;
+pub mod config;
+pub mod coordinator;
+pub mod date;
+pub mod date_input_mask;
+pub mod dom_events;
+pub mod error;
+pub mod format;
+pub mod headless;
+pub mod instances;
+pub mod keyboard;
+#[cfg(feature = "leptos")]
+pub mod leptos_component;
+pub mod linked;
+mod navigation;
+pub mod options;
+#[cfg(feature = "natural-language")]
+pub mod parse;
+mod persistence;
+mod position;
+pub mod query_param;
+pub mod range;
+pub mod style_names;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+#[cfg(feature = "web-component")]
+pub mod web_component;
+pub mod year_display;
+#[cfg(feature = "yew")]
+pub mod yew_component;
+
+use calendar::{CalendarDate, CalendarSystem};
+use color_scheme::ColorSchemeListener;
+use config::{
+    date_constraints::{CheckDateConstraints, ConstraintRule, DateRejection},
+    ConfigError,
+};
+use coordinator::OpenRegistration;
+use date::PickerDate;
+use error::DatePickerError;
+use headless::{PageCell, SelectionState, ViewChange, ViewMsg, ViewState};
+use keyboard::{KeyAction, KeyCombination};
+use navigation::{first_allowed_day_from, nearest_allowed_day, nearest_allowed_month};
+use options::{
+    ColorScheme, DaysLayout, DialogElement, OtherMonthClick, PickerOptions, Placement,
+    UnavailableNavigation,
+};
+use position::anchored_style;
+use range::DateRange;
+use style_names::*;
 
 /// reexport only necessary things for using the seed-datepicker
-pub use chrono_datepicker_core::config;
 pub use chrono_datepicker_core::dialog_view_type;
 
 /// `Model` describes the current datepicker state.
@@ -20,130 +70,1503 @@ pub struct Model<T>
 where
     T: HasDateConstraints + Default + Clone,
 {
-    /// value of the date that is selected
-    selected_date: Option<NaiveDate>,
+    /// selected date or range with its undo history
+    selection: SelectionState,
+
+    /// day cell that is currently under the mouse pointer
+    hovered_date: Option<NaiveDate>,
+
+    /// whether the dialog is shown
+    dialog_opened: bool,
+
+    /// whether the data of the displayed period are being loaded, the body is covered by an overlay
+    loading: bool,
+
+    /// viewed date and dialog type
+    view: ViewState,
+
+    /// dialog position style, describing the position of the dialog
+    dialog_position_style: Option<Style>,
+    /// element the dialog was opened at, if any
+    anchor: Option<web_sys::Element>,
+    /// window `scroll` and `resize` subscriptions repositioning the dialog, see `PickerOptions::follow_anchor`
+    anchor_streams: Vec<StreamHandle>,
+
+    /// configuration of the picker, should be passed in during init and not modified later
+    config: PickerConfig<T>,
+    /// copy of the date constraints of the `config`, which doesn't expose them,
+    /// the config is rebuilt with it when it changes at runtime
+    date_constraints: T,
+
+    /// options of the dialog, should be passed in during init and not modified later
+    options: PickerOptions,
+
+    /// reference to the cell that receives the keyboard focus after the `DialogViewType` changes
+    focus_ref: ElRef<web_sys::HtmlElement>,
+    /// the datepicker root, measured by the `Placement::Auto`
+    root_ref: ElRef<web_sys::HtmlElement>,
+    /// element wrapping the dialog that dispatches the `dom_events`, rendered even while the dialog is closed
+    events_ref: ElRef<web_sys::HtmlElement>,
+    /// node of the `portal` option the dialog is rendered into, moved to `document.body` when the dialog opens
+    portal_ref: ElRef<web_sys::HtmlElement>,
+    /// digits typed with the `KeyAction::TypeDigit` or with the `day_typing` option, cleared by any other action
+    typed_day: String,
+    /// clears the `typed_day` of the `day_typing` option after a pause in typing
+    typed_day_timeout: Option<CmdHandle>,
+    /// removes the dialog after the `closing_duration`, present while the dialog is closing
+    closing_timeout: Option<CmdHandle>,
+    /// closes the dialog after the `auto_close_after` inactivity, restarted by every message
+    auto_close_timeout: Option<CmdHandle>,
+    /// postponed `on_change` of the `change_debounce` option with the selection reported before it
+    debounced_change: Option<(CmdHandle, Option<NaiveDate>)>,
+    /// delay before a held previous/next button starts repeating
+    hold_delay: Option<CmdHandle>,
+    /// repeated navigation of a held previous/next button
+    hold_repeat: Option<StreamHandle>,
+    /// whether the held previous/next button started repeating, the click of its release is then ignored
+    navigation_held: bool,
+    /// delay before a held month cell zooms in, see the `zoom_on_long_press` option
+    month_hold_delay: Option<CmdHandle>,
+    /// whether the held month cell zoomed in, the click of its release is then ignored
+    month_held: bool,
+
+    /// listener of the `prefers-color-scheme` changes, only present with `ColorScheme::Auto`
+    color_scheme_listener: Option<ColorSchemeListener>,
+    /// preferred color scheme read from the `color_scheme_listener`, so that the `view` doesn't call into the browser
+    prefers_dark: bool,
+    open_registration: Option<OpenRegistration>,
+}
+
+impl<T: HasDateConstraints + Default + Clone> Model<T> {
+    /// model in the initial state of the `config`, the `color_scheme_listener` is only needed for `ColorScheme::Auto`
+    pub(crate) fn new(
+        config: PickerConfig<T>,
+        date_constraints: T,
+        options: PickerOptions,
+        color_scheme_listener: Option<ColorSchemeListener>,
+    ) -> Self {
+        Self {
+            selection: SelectionState::new(*config.initial_date()),
+            hovered_date: None,
+            dialog_opened: *config.initially_opened(),
+            loading: false,
+            view: ViewState::new(&config, &options),
+            dialog_position_style: None,
+            anchor: None,
+            anchor_streams: Vec::new(),
+            config,
+            date_constraints,
+            options,
+            focus_ref: ElRef::default(),
+            root_ref: ElRef::default(),
+            events_ref: ElRef::default(),
+            portal_ref: ElRef::default(),
+            typed_day: String::new(),
+            typed_day_timeout: None,
+            closing_timeout: None,
+            auto_close_timeout: None,
+            debounced_change: None,
+            hold_delay: None,
+            hold_repeat: None,
+            navigation_held: false,
+            month_hold_delay: None,
+            month_held: false,
+            prefers_dark: color_scheme_listener
+                .as_ref()
+                .is_some_and(ColorSchemeListener::prefers_dark),
+            color_scheme_listener,
+            open_registration: None,
+        }
+    }
+
+    /// model for the server-side rendering, it doesn't touch any browser API, so `view` can be rendered
+    /// outside of the browser, a fixed `today_provider` keeps the pre-rendered markup and the hydrated app in sync,
+    /// the `ColorScheme::Auto` is rendered light
+    pub fn prerendered(
+        config: PickerConfig<T>,
+        date_constraints: T,
+        options: PickerOptions,
+    ) -> Self {
+        Self::new(config, date_constraints, options, None)
+    }
+
+    /// selected value of the datepicker
+    pub fn selected_date(&self) -> &Option<NaiveDate> {
+        self.selection.selected_date()
+    }
+
+    /// date that determines the displayed year group, year or month of the dialog
+    pub fn viewed_date(&self) -> &NaiveDate {
+        &self.view.viewed_date
+    }
+
+    /// `DialogViewType` that is currently displayed
+    pub fn dialog_view_type(&self) -> &DialogViewType {
+        &self.view.dialog_view_type
+    }
+
+    /// whether the dialog is opened
+    pub fn is_open(&self) -> bool {
+        self.dialog_opened
+    }
+
+    /// whether the dialog plays its closing animation, see the `closing_duration` option
+    pub fn is_closing(&self) -> bool {
+        self.closing_timeout.is_some()
+    }
+
+    /// whether the body of the dialog is covered by the loading overlay
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    /// selected value of the datepicker converted to another `PickerDate` type,
+    /// `None` also if the selected date can't be represented by that type
+    pub fn selected_date_as<D: PickerDate>(&self) -> Option<D> {
+        self.selection.selected_date.and_then(D::from_naive_date)
+    }
+
+    /// start and end of the selected range converted to another `PickerDate` type,
+    /// `None` also if any of them can't be represented by that type
+    pub fn selected_range_as<D: PickerDate>(&self) -> Option<(D, D)> {
+        let range = self.selection.selected_range.as_ref()?;
+        Some((
+            D::from_naive_date(range.start())?,
+            D::from_naive_date(range.end())?,
+        ))
+    }
+
+    /// selection written in the `value_format` option, the range as `start – end` with `range_selection`
+    pub fn formatted_value(&self) -> Option<String> {
+        let value_format = self.options.value_format();
+        if *self.options.range_selection() {
+            self.selection.selected_range.and_then(|range| {
+                Some(format!(
+                    "{} – {}",
+                    value_format.format(&range.start())?,
+                    value_format.format(&range.end())?
+                ))
+            })
+        } else {
+            self.selection
+                .selected_date
+                .and_then(|date| value_format.format(&date))
+        }
+    }
+
+    /// date staged in the dialog that still waits for the confirmation
+    pub fn pending_date(&self) -> &Option<NaiveDate> {
+        self.selection.pending_date()
+    }
+
+    /// day cell that is currently under the mouse pointer
+    pub fn hovered_date(&self) -> &Option<NaiveDate> {
+        &self.hovered_date
+    }
+
+    /// selected range of dates (start, end), only used with `range_selection`
+    pub fn selected_range(&self) -> &Option<DateRange> {
+        self.selection.selected_range()
+    }
+
+    /// start of the range that waits for its end to be clicked
+    pub fn range_start(&self) -> &Option<NaiveDate> {
+        self.selection.range_start()
+    }
+
+    /// range staged in the dialog that still waits for the confirmation
+    pub fn pending_range(&self) -> &Option<DateRange> {
+        self.selection.pending_range()
+    }
+
+    /// renderer-agnostic selection state, see the `headless` module
+    pub fn selection_state(&self) -> &SelectionState {
+        &self.selection
+    }
+
+    pub fn config(&self) -> &PickerConfig<T> {
+        &self.config
+    }
+
+    pub fn options(&self) -> &PickerOptions {
+        &self.options
+    }
+
+    /// closest selectable date after the selected one (or the viewed one if nothing is selected)
+    pub fn next_allowed_date(&self) -> Option<NaiveDate> {
+        nearest_allowed_day(&self.config, &self.selection_or_viewed_date(), true)
+    }
+
+    /// closest selectable date before the selected one (or the viewed one if nothing is selected)
+    pub fn previous_allowed_date(&self) -> Option<NaiveDate> {
+        nearest_allowed_day(&self.config, &self.selection_or_viewed_date(), false)
+    }
+
+    /// whether the previous button can be used, limited by the `navigation_min` option if there is one
+    pub fn can_navigate_previous(&self) -> bool {
+        self.view.can_navigate_previous(&self.config, &self.options)
+    }
+
+    /// whether the next button can be used, limited by the `navigation_max` option if there is one
+    pub fn can_navigate_next(&self) -> bool {
+        self.view.can_navigate_next(&self.config, &self.options)
+    }
+
+    /// renderer-agnostic viewed date and dialog type, see the `headless` module
+    pub fn view_state(&self) -> &ViewState {
+        &self.view
+    }
+
+    /// first and last day of the period displayed by the dialog viewing the `viewed_date`
+    fn page_bounds(&self, viewed_date: &NaiveDate) -> (NaiveDate, NaiveDate) {
+        self.view.page_bounds(viewed_date, &self.options)
+    }
+
+    /// `id` of an element of the dialog, if the `id_prefix` option is set
+    fn element_id(&self, name: &str) -> Option<String> {
+        self.options
+            .id_prefix()
+            .as_ref()
+            .map(|id_prefix| format!("{}-{}", id_prefix, name))
+    }
+
+    /// whether the days view displays a single week, see `DaysLayout::WeekStrip`
+    fn is_week_strip(&self) -> bool {
+        self.view.is_week_strip(&self.options)
+    }
+
+    /// the `calendar_system` option or the Gregorian calendar
+    fn calendar(&self) -> &dyn CalendarSystem {
+        format::calendar(&self.options)
+    }
+
+    /// whether both dates are in the same month of the calendar system
+    fn is_same_month(&self, a: &NaiveDate, b: &NaiveDate) -> bool {
+        let (a, b) = (
+            self.calendar().calendar_date(a),
+            self.calendar().calendar_date(b),
+        );
+        a.year == b.year && a.month == b.month
+    }
+
+    /// cells of the displayed page, see `ViewState::cells`
+    fn cells(&self) -> Vec<PageCell> {
+        self.view
+            .cells(&self.config, &self.options, &self.selection)
+    }
+
+    /// title of the dialog, see `format::format_title`
+    fn title_text(&self) -> String {
+        self.view.title(&self.config, &self.options)
+    }
+
+    /// day of the viewed month with the number typed with the `KeyAction::TypeDigit`, if there is such a day
+    fn typed_day_date(&self) -> Option<NaiveDate> {
+        let day = self.typed_day.parse().ok()?;
+        let month = self.calendar().calendar_date(&self.view.viewed_date);
+        self.calendar()
+            .to_naive_date(&CalendarDate { day, ..month })
+    }
+
+    /// whether the `Enter` is left to the `KeyAction::SelectTypedDay` of the dialog root,
+    /// because a day number was typed, instead of selecting the focused day cell
+    fn enter_selects_typed_day(&self) -> bool {
+        !self.typed_day.is_empty()
+            && self
+                .options
+                .key_bindings()
+                .action(&KeyCombination::new("Enter"))
+                == Some(KeyAction::SelectTypedDay)
+    }
+
+    /// position of the dialog opened at the `anchor`
+    fn anchored_style(&self, anchor: &web_sys::Element) -> Style {
+        let dialog = self.root_ref.get();
+        anchored_style(
+            anchor,
+            *self.options.fixed_position(),
+            *self.options.placement(),
+            dialog.as_deref(),
+        )
+    }
+
+    /// whether holding a month cell displays its days, which the `selection_type` of months doesn't allow
+    fn zooms_on_long_press(&self) -> bool {
+        *self.options.zoom_on_long_press() && self.config.selection_type() == &DialogViewType::Days
+    }
+
+    /// whether there is a previous selection to restore with `Msg::Undo`
+    pub fn can_undo(&self) -> bool {
+        self.selection.can_undo()
+    }
+
+    /// whether there is an undone selection to restore with `Msg::Redo`
+    pub fn can_redo(&self) -> bool {
+        self.selection.can_redo()
+    }
+
+    /// value reported in the `SelectionChange`, the end of the selected range in the range mode
+    fn reported_selection(&self) -> Option<NaiveDate> {
+        self.selection.reported(*self.options.range_selection())
+    }
+
+    /// selection last reported to the `on_change`, it differs from the current one while its change is debounced
+    fn settled_selection(&self) -> Option<NaiveDate> {
+        self.debounced_change
+            .as_ref()
+            .map_or_else(|| self.reported_selection(), |(_, old)| *old)
+    }
+
+    fn selection_or_viewed_date(&self) -> NaiveDate {
+        self.displayed_selection().unwrap_or(self.view.viewed_date)
+    }
+
+    /// replaces the date constraints of the config, the selected date or range is cleared if the new constraints
+    /// forbid it, returns whether it was cleared, so that the caller can handle the change of the selection,
+    /// if the config with the new constraints is invalid, the current one is kept and the error is returned
+    pub fn update_constraints(&mut self, date_constraints: T) -> Result<bool, ConfigError> {
+        let mut builder = config::rebuild(&self.config);
+        if let Some(initial_date) = self
+            .config
+            .initial_date()
+            .filter(|initial_date| !date_constraints.is_day_forbidden(initial_date))
+        {
+            builder.initial_date(initial_date);
+        }
+        self.config = builder
+            .date_constraints(date_constraints.clone())
+            .build()
+            .map_err(|error| ConfigError::Builder(error.to_string()))?;
+        self.date_constraints = date_constraints;
+        Ok(self.revalidate())
+    }
+
+    /// changes the config at runtime, `change` receives a builder with all the current values,
+    /// if the changed config is invalid, the current one is kept and the validation error is returned,
+    /// otherwise returns whether the selected date or range was cleared, because the new config forbids it
+    pub fn update_config(
+        &mut self,
+        change: impl FnOnce(&mut PickerConfigBuilder<T>),
+    ) -> Result<bool, ConfigError> {
+        let mut builder = config::rebuild(&self.config);
+        builder.date_constraints(self.date_constraints.clone());
+        if let Some(initial_date) = *self.config.initial_date() {
+            builder.initial_date(initial_date);
+        }
+        change(&mut builder);
+        self.config = builder
+            .build()
+            .map_err(|error| ConfigError::Builder(error.to_string()))?;
+        Ok(self.revalidate())
+    }
+
+    /// replaces the `PickerOptions` at runtime
+    pub fn set_options(&mut self, options: PickerOptions) {
+        self.options = options;
+    }
+
+    /// makes the state consistent with a changed config,
+    /// returns whether the selected date or range was cleared, because the config forbids it
+    fn revalidate(&mut self) -> bool {
+        self.view.viewed_date = nearest_allowed_month(&self.config, &self.view.viewed_date);
+        if view_type_rank(&self.view.dialog_view_type)
+            > view_type_rank(self.config.selection_type())
+        {
+            self.view.dialog_view_type = *self.config.selection_type();
+        }
+        self.selection.drop_forbidden(&self.config, &self.options)
+    }
+
+    /// whether the dialog is displayed with the dark color scheme
+    pub fn is_dark(&self) -> bool {
+        match self.options.color_scheme() {
+            ColorScheme::Light => false,
+            ColorScheme::Dark => true,
+            ColorScheme::Auto => self.prefers_dark,
+        }
+    }
+
+    /// whether the day can't be clicked, either because of the constraints,
+    /// or because it is outside of the allowed range length while the end of a range is being picked
+    fn is_day_forbidden(&self, date: &NaiveDate) -> bool {
+        self.config.is_day_forbidden(date)
+            || self
+                .selection
+                .is_outside_range_window(date, &self.config, &self.options)
+    }
+
+    /// selected or staged range, or the range between the `range_start` and the hovered date
+    fn displayed_range(&self) -> Option<DateRange> {
+        match (self.selection.range_start, self.hovered_date) {
+            (Some(range_start), Some(hovered_date)) => {
+                Some(DateRange::new(range_start, hovered_date))
+            }
+            (Some(range_start), None) => Some(DateRange::new(range_start, range_start)),
+            (None, _) => self.selection.displayed_range(),
+        }
+    }
+
+    /// reason why the `date` can't be selected, if the `forbidden_reason` option provides one
+    fn forbidden_reason(&self, date: &NaiveDate) -> Option<String> {
+        self.options
+            .forbidden_reason()
+            .as_ref()
+            .and_then(|forbidden_reason| forbidden_reason(date))
+    }
+
+    /// category of the rule that forbids the `date`, if the `forbidden_rule` option provides one
+    fn forbidden_rule(&self, date: &NaiveDate) -> Option<ConstraintRule> {
+        self.options
+            .forbidden_rule()
+            .as_ref()
+            .and_then(|forbidden_rule| forbidden_rule(date))
+    }
+
+    /// date displayed as selected in the dialog, the pending one takes precedence
+    fn displayed_selection(&self) -> Option<NaiveDate> {
+        self.selection.displayed()
+    }
+
+    /// date of the cell that should have the keyboard focus in the current view,
+    /// the allowed typed day or the selected date if it is displayed, otherwise the viewed date
+    fn focus_target(&self) -> NaiveDate {
+        let (first_day, last_day) = self.page_bounds(&self.view.viewed_date);
+        self.typed_day_date()
+            .filter(|typed_day| {
+                self.view.dialog_view_type == DialogViewType::Days
+                    && !self.is_day_forbidden(typed_day)
+            })
+            .or_else(|| self.displayed_selection())
+            .filter(|date| (first_day..=last_day).contains(date))
+            .unwrap_or(self.view.viewed_date)
+    }
+}
+
+impl<T: CheckDateConstraints + Default + Clone> Model<T> {
+    /// `Ok` if the `date` can be selected in the current state of the dialog, e.g. before sending
+    /// a `Msg::DateSelected` with a date from a deep link, otherwise the first reason why it can't,
+    /// the days that only violate the range length options are reported as `DateRejection::Forbidden`
+    pub fn validate(&self, date: NaiveDate) -> Result<(), DateRejection> {
+        self.date_constraints.check_day(&date)?;
+        if self.is_day_forbidden(&date) {
+            return Err(DateRejection::Forbidden);
+        }
+        Ok(())
+    }
+}
+
+impl<T: HasDateConstraints + Default + Clone + fmt::Debug> fmt::Debug for Model<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Model")
+            .field("selection", &self.selection)
+            .field("hovered_date", &self.hovered_date)
+            .field("dialog_opened", &self.dialog_opened)
+            .field("loading", &self.loading)
+            .field("view", &self.view)
+            .field("dialog_position_style", &self.dialog_position_style)
+            .field("anchor", &self.anchor)
+            .field("typed_day", &self.typed_day)
+            .field("config", &self.config)
+            .field("options", &self.options)
+            .finish()
+    }
+}
+
+/// Monday of the week that contains the `date`
+pub(crate) fn week_start(date: &NaiveDate) -> NaiveDate {
+    date.checked_sub_signed(Duration::days(date.weekday().num_days_from_monday().into()))
+        .unwrap_or(NaiveDate::MIN)
+}
+
+/// rank of the `DialogViewType` from the largest to the smallest period
+pub(crate) fn view_type_rank(dialog_view_type: &DialogViewType) -> u8 {
+    match dialog_view_type {
+        DialogViewType::Years => 0,
+        DialogViewType::Months => 1,
+        DialogViewType::Days => 2,
+    }
+}
+
+/// `DialogViewType` displaying the smaller periods than the given one, `None` for the days
+fn smaller_view_type(dialog_view_type: &DialogViewType) -> Option<DialogViewType> {
+    match dialog_view_type {
+        DialogViewType::Years => Some(DialogViewType::Months),
+        DialogViewType::Months => Some(DialogViewType::Days),
+        DialogViewType::Days => None,
+    }
+}
+
+/// pause in typing after which the `day_typing` option starts a new day number
+const DAY_TYPING_TIMEOUT_MS: u32 = 1000;
+
+/// how long the previous/next button has to be held before the navigation starts repeating,
+/// and a month cell before it zooms in
+const HOLD_DELAY_MS: u32 = 500;
+
+/// pause between the repeated navigations of a held previous/next button
+const HOLD_INTERVAL_MS: u32 = 100;
+
+/// `init` describes what should happen when your app started,
+/// the `date_constraints` are the same ones the `config` was built with, because the config doesn't expose them.
+pub fn init<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    url: Url,
+    orders: &mut impl Orders<Ms>,
+    config: PickerConfig<T>,
+    date_constraints: T,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+) -> Model<T> {
+    init_with_options(
+        url,
+        orders,
+        config,
+        date_constraints,
+        PickerOptions::default(),
+        to_msg,
+    )
+}
+
+/// `init_with_options` is the same as `init`, but it also sets the `PickerOptions` of the dialog.
+pub fn init_with_options<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    _: Url,
+    orders: &mut impl Orders<Ms>,
+    config: PickerConfig<T>,
+    date_constraints: T,
+    options: PickerOptions,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+) -> Model<T> {
+    let color_scheme_listener = match options.color_scheme() {
+        ColorScheme::Auto => {
+            let msg_sender = orders.msg_sender();
+            let to_msg = to_msg.clone();
+            ColorSchemeListener::new(move || {
+                msg_sender(Some(to_msg.clone()(Msg::ColorSchemeChanged)))
+            })
+        }
+        _ => None,
+    };
+    let restored_range = match options.persistence_key() {
+        Some(persistence_key) if *options.range_selection() && config.initial_date().is_none() => {
+            persistence::restore_range(&config, persistence_key)
+        }
+        _ => None,
+    };
+    let config = match options.persistence_key() {
+        Some(persistence_key) if !*options.range_selection() && config.initial_date().is_none() => {
+            persistence::restore(config, &date_constraints, persistence_key)
+        }
+        _ => config,
+    };
+    if *options.auto_select_first_allowed()
+        && config.initial_date().is_none()
+        && restored_range.is_none()
+    {
+        if let Some(first_allowed) = first_allowed_day_from(&config, &options.today()) {
+            orders.send_msg(to_msg.clone()(Msg::FirstAllowedSelected(first_allowed)));
+        }
+    }
+    let open_registration = options.open_coordinator().as_ref().map(|open_coordinator| {
+        let msg_sender = orders.msg_sender();
+        open_coordinator.register(move || msg_sender(Some(to_msg.clone()(Msg::CloseDialog))))
+    });
+    let mut model = Model {
+        open_registration,
+        ..Model::new(config, date_constraints, options, color_scheme_listener)
+    };
+    if let Some(restored_range) = restored_range {
+        model.selection.selected_range = Some(restored_range);
+        model.view.viewed_date = restored_range.start();
+    }
+    if model.dialog_opened {
+        mount_portal(&model, orders);
+    }
+    model
+}
+
+/// `Msg` describes the different events you can modify state with.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Msg {
+    DateSelected(NaiveDate),
+    /// `Enter` on a day cell, selects the date and closes the dialog once the selection is complete
+    DateEntered(NaiveDate),
+    /// `Space` on a day cell, toggles the date without closing the dialog
+    DateToggled(NaiveDate),
+    MonthSelected(MonthNumber),
+    YearSelected(YearNumber),
+    /// open the dialog, optionally at the given (left, top) position
+    OpenDialog(Option<(String, String)>),
+    /// open the dialog below the given element
+    OpenDialogAt(web_sys::Element),
+    /// the page was scrolled or resized while the dialog is open at an anchor
+    AnchorMoved,
+    /// a key bound in the `KeyBindings` was pressed inside of the dialog
+    KeyPressed(KeyCombination),
+    /// a digit of a day number was typed in the days view, see the `day_typing` option
+    DayDigitTyped(char),
+    /// the pause after typing a day number was long enough to start typing another one
+    DayTypingTimedOut,
+    /// the dialog was inactive for the `auto_close_after` duration
+    AutoCloseTimedOut,
+    /// the `change_debounce` passed after the last selection change, the `on_change` is sent
+    ChangeSettled,
+    CloseDialog,
+    /// the `closing_duration` of the closing animation passed, the dialog is removed
+    ClosingFinished,
+    PreviousButtonClicked,
+    NextButtonClicked,
+
+    /// a day of the previous or the next month was clicked in the days view, see `OtherMonthClick`
+    OtherMonthDayClicked(NaiveDate),
+
+    /// the previous (`false`) or next (`true`) button was pressed, holding it repeats the navigation
+    NavigationPressed(bool),
+    /// the previous/next button was held long enough to start repeating,
+    /// the click of its release doesn't navigate again
+    NavigationHeld(bool),
+    /// next step of the repeated navigation of a held previous/next button
+    NavigationRepeated(bool),
+    /// the held previous/next button was released or left by the pointer
+    NavigationReleased,
+
+    /// moves the viewed date by the given number of months and years at once, e.g. for "+3 months" buttons
+    ShiftView {
+        months: i32,
+        years: i32,
+    },
+
+    /// clicks on the dialog title change the `DialogViewType`
+    DialogTitleClicked,
+
+    /// the dialog title was double-clicked, see the `zoom_on_double_click` option
+    DialogTitleDoubleClicked,
+
+    /// the month cell was pressed, holding it zooms in, see the `zoom_on_long_press` option
+    MonthPressed(MonthNumber),
+    /// the month cell was held long enough to display its days,
+    /// the click of its release doesn't select the month again
+    MonthHeld(MonthNumber),
+    /// the held month cell was released or left by the pointer
+    MonthReleased,
+
+    /// displays the smaller period of the viewed date, e.g. the months of the viewed year,
+    /// as long as the `selection_type` allows it
+    ZoomIn,
+
+    /// displays the larger period of the viewed date, e.g. the years around the viewed year
+    ZoomOut,
+
+    /// displays the viewed date in the given `DialogViewType`, ignored if it displays smaller periods
+    /// than the `selection_type`, e.g. the days when only the months can be selected
+    SetViewType(DialogViewType),
+
+    /// the `prefers-color-scheme` of the browser changed, the dialog only needs to be re-rendered
+    ColorSchemeChanged,
+
+    /// selects the date found by `init` with the `auto_select_first_allowed` option,
+    /// as a one-day range with `range_selection`, without closing the dialog
+    FirstAllowedSelected(NaiveDate),
+
+    /// covers (`true`) or uncovers the body of the dialog with the loading overlay,
+    /// e.g. while the availability of the viewed month is fetched
+    SetLoading(bool),
+
+    /// selects the pending date, only used with `confirm_selection`
+    ConfirmClicked,
+
+    /// discards the pending date and closes the dialog, only used with `confirm_selection`
+    CancelClicked,
+
+    /// the mouse pointer entered (`Some`) or left (`None`) a day cell
+    DayHovered(Option<NaiveDate>),
+
+    /// selects the closest selectable date after the current selection
+    SelectNextAllowed,
+
+    /// selects the closest selectable date before the current selection
+    SelectPreviousAllowed,
+
+    /// restores the selection before the last change
+    Undo,
+
+    /// restores the selection changed by the last `Msg::Undo`
+    Redo,
+}
+
+impl Msg {
+    /// `Msg::DateSelected` with a date of another `PickerDate` type,
+    /// `None` if the date can't be represented as a `NaiveDate`
+    pub fn date_selected<D: PickerDate>(date: &D) -> Option<Self> {
+        date.to_naive_date().map(Msg::DateSelected)
+    }
+
+    /// `Msg::DateSelected` with the date of the `year`, `month` and `day`, e.g. from a deep link,
+    /// an error instead of a panic if they don't make up a date
+    pub fn try_date_selected(year: i32, month: u32, day: u32) -> Result<Self, DatePickerError> {
+        date::try_ymd(year, month, day).map(Msg::DateSelected)
+    }
+}
+
+/// `InteractionEvent` describes a change of the dialog caused by the user or the app,
+/// reported to the `on_interaction` option, e.g. for the product analytics.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InteractionEvent {
+    Opened,
+    /// another period was displayed, with the new viewed date
+    Navigated(NaiveDate),
+    /// another `DialogViewType` was displayed
+    Zoomed(DialogViewType),
+    /// a date was selected, the end of the range in the range mode
+    Selected(NaiveDate),
+    /// the selection was cleared
+    Cleared,
+    Closed,
+}
+
+/// `update` describes how to handle each `Msg`,
+/// `on_change` creates the message sent with the newly selected date (the end of a range in the range mode).
+pub fn update<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    msg: Msg,
+    model: &mut Model<T>,
+    orders: &mut impl Orders<Ms>,
+    on_change: impl FnOnce(Option<NaiveDate>) -> Ms,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+) {
+    if let Some(new_selection) = update_model(msg, model, orders, to_msg) {
+        orders.send_msg(on_change(new_selection));
+    }
+}
+
+/// `update` reporting the newly selected date as another `PickerDate` type, e.g. `time::Date`,
+/// a date that can't be represented by that type is reported as `None`
+pub fn update_as<
+    D: PickerDate,
+    Ms: 'static,
+    T: HasDateConstraints + std::default::Default + Clone,
+>(
+    msg: Msg,
+    model: &mut Model<T>,
+    orders: &mut impl Orders<Ms>,
+    on_change: impl FnOnce(Option<D>) -> Ms,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+) {
+    update(
+        msg,
+        model,
+        orders,
+        |selection| on_change(selection.and_then(D::from_naive_date)),
+        to_msg,
+    )
+}
+
+/// handles the `msg`, returns the new selection (the end of a range in the range mode) if it changed,
+/// `Some(None)` means that the selection was cleared
+pub(crate) fn update_model<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    msg: Msg,
+    model: &mut Model<T>,
+    orders: &mut impl Orders<Ms>,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+) -> Option<Option<NaiveDate>> {
+    let settles_change = msg == Msg::ChangeSettled;
+    let restarts_auto_close = msg != Msg::AutoCloseTimedOut && !settles_change;
+    let was_open = model.dialog_opened;
+    let old_selection = model.settled_selection();
+    let new_selection = match model.options.on_interaction().clone() {
+        Some(on_interaction) => {
+            handle_reported_msg(msg, model, orders, to_msg.clone(), &*on_interaction)
+        }
+        None => handle_msg(msg, model, orders, to_msg.clone()),
+    };
+    match model.options.auto_close_after() {
+        Some(auto_close_after) if model.dialog_opened && restarts_auto_close => {
+            let timeout_ms = u32::try_from(auto_close_after.as_millis()).unwrap_or(u32::MAX);
+            model.auto_close_timeout =
+                Some(orders.perform_cmd_with_handle(cmds::timeout(timeout_ms, {
+                    let to_msg = to_msg.clone();
+                    move || to_msg(Msg::AutoCloseTimedOut)
+                })));
+        }
+        _ if !model.dialog_opened => model.auto_close_timeout = None,
+        _ => {}
+    }
+    let new_selection = match (model.options.change_debounce(), new_selection) {
+        (Some(change_debounce), Some(_)) if !settles_change => {
+            let debounce_ms = u32::try_from(change_debounce.as_millis()).unwrap_or(u32::MAX);
+            let handle = orders.perform_cmd_with_handle(cmds::timeout(debounce_ms, move || {
+                to_msg(Msg::ChangeSettled)
+            }));
+            model.debounced_change = Some((handle, old_selection));
+            None
+        }
+        _ => new_selection,
+    };
+    if let (Some(persistence_key), Some(_)) = (model.options.persistence_key(), new_selection) {
+        if *model.options.range_selection() {
+            persistence::store_range(persistence_key, *model.selection.selected_range());
+        } else {
+            persistence::store(persistence_key, model.reported_selection());
+        }
+    }
+    if *model.options.notify_selection() && new_selection.is_some() {
+        orders.notify(SelectionNotification {
+            id_prefix: model.options.id_prefix().clone(),
+            change: SelectionChange {
+                old: old_selection,
+                new: model.reported_selection(),
+            },
+        });
+    }
+    if *model.options.dom_events() {
+        dispatch_dom_events(model, orders, was_open, new_selection.is_some());
+    }
+    new_selection
+}
+
+/// dispatches the `dom_events` of the changed selection and of the opened or closed dialog after the next render,
+/// so that their target is rendered even if the selection changed before the first render
+fn dispatch_dom_events<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    model: &Model<T>,
+    orders: &mut impl Orders<Ms>,
+    was_open: bool,
+    selection_changed: bool,
+) {
+    let mut events = Vec::new();
+    if selection_changed {
+        events.push(dom_events::CHANGE_EVENT);
+    }
+    match (was_open, model.dialog_opened) {
+        (false, true) => events.push(dom_events::OPEN_EVENT),
+        (true, false) => events.push(dom_events::CLOSE_EVENT),
+        _ => {}
+    }
+    if events.is_empty() {
+        return;
+    }
+    let events_ref = model.events_ref.clone();
+    let selection = model.reported_selection();
+    orders.after_next_render(move |_| {
+        if let Some(target) = events_ref.get() {
+            for name in events {
+                dom_events::dispatch(&target, name, selection);
+            }
+        }
+    });
+}
+
+/// `handle_msg` that reports the changes of the dialog to the `on_interaction` option
+fn handle_reported_msg<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    msg: Msg,
+    model: &mut Model<T>,
+    orders: &mut impl Orders<Ms>,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+    on_interaction: &dyn Fn(InteractionEvent),
+) -> Option<Option<NaiveDate>> {
+    let (was_open, old_view_type, old_viewed_date) = (
+        model.dialog_opened,
+        model.view.dialog_view_type,
+        model.view.viewed_date,
+    );
+    let new_selection = handle_msg(msg, model, orders, to_msg);
+    if !was_open && model.dialog_opened {
+        on_interaction(InteractionEvent::Opened);
+    }
+    if model.view.dialog_view_type != old_view_type {
+        on_interaction(InteractionEvent::Zoomed(model.view.dialog_view_type));
+    } else if model.view.viewed_date != old_viewed_date && new_selection.is_none() {
+        on_interaction(InteractionEvent::Navigated(model.view.viewed_date));
+    }
+    match new_selection {
+        Some(Some(date)) => on_interaction(InteractionEvent::Selected(date)),
+        Some(None) => on_interaction(InteractionEvent::Cleared),
+        None => {}
+    }
+    if was_open && !model.dialog_opened {
+        on_interaction(InteractionEvent::Closed);
+    }
+    new_selection
+}
+
+fn handle_msg<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    msg: Msg,
+    model: &mut Model<T>,
+    orders: &mut impl Orders<Ms>,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+) -> Option<Option<NaiveDate>> {
+    let mut new_selection = None;
+    match msg {
+        Msg::DateSelected(new_date) => {
+            model.view.viewed_date = new_date;
+            if *model.options.range_selection() && *model.options.confirm_selection() {
+                model.selection.stage_range_end(new_date);
+            } else if *model.options.range_selection() {
+                if model.selection.select_range_end(new_date).is_some() {
+                    if *model.options.close_on_select() {
+                        orders.send_msg(to_msg(Msg::CloseDialog));
+                    }
+                    new_selection = Some(Some(new_date));
+                }
+            } else if *model.options.confirm_selection() {
+                model.selection.stage(new_date);
+            } else {
+                select_date(new_date, model, orders, to_msg);
+                new_selection = Some(Some(new_date));
+            }
+        }
+        Msg::DateEntered(new_date) => {
+            new_selection = handle_msg(Msg::DateSelected(new_date), model, orders, to_msg.clone());
+            if *model.options.confirm_selection() {
+                new_selection = handle_msg(Msg::ConfirmClicked, model, orders, to_msg.clone());
+            }
+            if new_selection.is_some() {
+                orders.send_msg(to_msg(Msg::CloseDialog));
+            }
+        }
+        Msg::DateToggled(new_date) => {
+            model.view.viewed_date = new_date;
+            if *model.options.range_selection() && *model.options.confirm_selection() {
+                model.selection.stage_range_end(new_date);
+            } else if *model.options.range_selection() {
+                if model.selection.select_range_end(new_date).is_some() {
+                    new_selection = Some(Some(new_date));
+                }
+            } else if *model.options.confirm_selection() {
+                model.selection.toggle_staged(new_date);
+            } else {
+                new_selection = Some(model.selection.toggle(new_date));
+            }
+        }
+        Msg::MonthSelected(_) if model.month_held => model.month_held = false,
+        Msg::MonthSelected(new_month) => {
+            update_view(ViewMsg::MonthSelected(new_month), model, orders, to_msg)
+        }
+        Msg::YearSelected(new_year) => {
+            update_view(ViewMsg::YearSelected(new_year), model, orders, to_msg)
+        }
+        Msg::OpenDialog(position) => {
+            if let Some(open_registration) = &model.open_registration {
+                open_registration.opened();
+            }
+            model.dialog_opened = true;
+            model.closing_timeout = None;
+            model.view.viewed_date = nearest_allowed_month(&model.config, &model.view.viewed_date);
+            if let Some((left, top)) = position {
+                model.dialog_position_style = Some(style! {
+                    St::Left => left,
+                    St::Top => top,
+                });
+            }
+            reveal_selected_year(model, orders);
+            mount_portal(model, orders);
+            show_native_dialog(model, orders);
+        }
+        Msg::OpenDialogAt(_) if model.options.anchor_name().is_some() => {
+            new_selection = handle_msg(Msg::OpenDialog(None), model, orders, to_msg);
+        }
+        Msg::OpenDialogAt(anchor) => {
+            model.dialog_position_style = Some(model.anchored_style(&anchor));
+            model.anchor = Some(anchor);
+            if *model.options.placement() == Placement::Auto {
+                let to_msg = to_msg.clone();
+                orders.after_next_render(move |_| to_msg(Msg::AnchorMoved));
+            }
+            if *model.options.follow_anchor() {
+                model.anchor_streams = [Ev::Scroll, Ev::Resize]
+                    .iter()
+                    .map(|event| {
+                        let to_msg = to_msg.clone();
+                        orders.stream_with_handle(streams::window_event(event.clone(), |_| {
+                            to_msg(Msg::AnchorMoved)
+                        }))
+                    })
+                    .collect();
+            }
+            orders.send_msg(to_msg.clone()(Msg::OpenDialog(None)));
+        }
+        Msg::AnchorMoved => {
+            if let Some(anchor) = &model.anchor {
+                model.dialog_position_style = Some(model.anchored_style(anchor));
+            }
+        }
+        Msg::KeyPressed(combination) => {
+            let action = model.options.key_bindings().action(&combination);
+            if action != Some(KeyAction::TypeDigit) {
+                model.typed_day.clear();
+            }
+            let msg = match action {
+                Some(KeyAction::NextMonth) => Some(Msg::NextButtonClicked),
+                Some(KeyAction::PreviousMonth) => Some(Msg::PreviousButtonClicked),
+                Some(KeyAction::SelectToday) => Some(model.options.today())
+                    .filter(|today| !model.is_day_forbidden(today))
+                    .map(Msg::DateSelected),
+                Some(KeyAction::Close) => Some(Msg::CloseDialog),
+                Some(KeyAction::ZoomOut) => Some(Msg::ZoomOut),
+                Some(KeyAction::NextYear) => Some(Msg::ShiftView {
+                    months: 0,
+                    years: 1,
+                }),
+                Some(KeyAction::PreviousYear) => Some(Msg::ShiftView {
+                    months: 0,
+                    years: -1,
+                }),
+                Some(KeyAction::TypeDigit) => {
+                    model.typed_day.push_str(&combination.key);
+                    None
+                }
+                Some(KeyAction::SelectTypedDay) => model
+                    .typed_day_date()
+                    .filter(|date| !model.is_day_forbidden(date))
+                    .map(Msg::DateSelected),
+                None => None,
+            };
+            if let Some(msg) = msg {
+                orders.send_msg(to_msg(msg));
+            }
+        }
+        Msg::DayDigitTyped(digit) => {
+            model.typed_day.push(digit);
+            model.typed_day_timeout = Some(orders.perform_cmd_with_handle(cmds::timeout(
+                DAY_TYPING_TIMEOUT_MS,
+                {
+                    let to_msg = to_msg.clone();
+                    move || to_msg(Msg::DayTypingTimedOut)
+                },
+            )));
+            if let Some(typed_day) = model.typed_day_date() {
+                if !model.is_day_forbidden(&typed_day) {
+                    model.view.viewed_date = typed_day;
+                    focus_cell_after_render(model, orders);
+                }
+            }
+        }
+        Msg::AutoCloseTimedOut => {
+            new_selection = handle_msg(Msg::CloseDialog, model, orders, to_msg);
+        }
+        Msg::ChangeSettled => {
+            // the changes cancelling each other out during the debounce aren't reported
+            if let Some((_, settled)) = model.debounced_change.take() {
+                let reported = model.reported_selection();
+                if reported != settled {
+                    new_selection = Some(reported);
+                }
+            }
+        }
+        Msg::DayTypingTimedOut => {
+            model.typed_day.clear();
+            model.typed_day_timeout = None;
+        }
+        Msg::CloseDialog if model.closing_timeout.is_some() => {}
+        Msg::CloseDialog if model.dialog_opened && model.options.closing_duration().is_some() => {
+            let closing_ms = model.options.closing_duration().map_or(0, |duration| {
+                u32::try_from(duration.as_millis()).unwrap_or(u32::MAX)
+            });
+            model.closing_timeout = Some(
+                orders.perform_cmd_with_handle(cmds::timeout(closing_ms, move || {
+                    to_msg(Msg::ClosingFinished)
+                })),
+            );
+        }
+        Msg::CloseDialog | Msg::ClosingFinished => {
+            model.closing_timeout = None;
+            model.dialog_opened = false;
+            model.selection.discard_unfinished();
+            model.hovered_date = None;
+            model.anchor = None;
+            model.anchor_streams.clear();
+            model.typed_day.clear();
+            model.typed_day_timeout = None;
+            model.hold_delay = None;
+            model.hold_repeat = None;
+            model.navigation_held = false;
+            model.month_hold_delay = None;
+            model.month_held = false;
+        }
+        Msg::PreviousButtonClicked | Msg::NextButtonClicked if model.navigation_held => {
+            model.navigation_held = false;
+        }
+        Msg::PreviousButtonClicked => update_view(ViewMsg::Previous, model, orders, to_msg),
+        Msg::NextButtonClicked => update_view(ViewMsg::Next, model, orders, to_msg),
+        Msg::OtherMonthDayClicked(date) => match model.options.other_month_click() {
+            OtherMonthClick::Select => {
+                new_selection = handle_msg(Msg::DateSelected(date), model, orders, to_msg)
+            }
+            OtherMonthClick::Navigate => {
+                update_view(ViewMsg::Navigate(date), model, orders, to_msg)
+            }
+        },
+        Msg::NavigationPressed(forward) => {
+            model.hold_repeat = None;
+            model.navigation_held = false;
+            model.hold_delay = Some(orders.perform_cmd_with_handle(cmds::timeout(
+                HOLD_DELAY_MS,
+                {
+                    let to_msg = to_msg.clone();
+                    move || to_msg(Msg::NavigationHeld(forward))
+                },
+            )));
+        }
+        Msg::NavigationHeld(forward) => {
+            model.hold_delay = None;
+            model.navigation_held = true;
+            model.hold_repeat = Some(
+                orders.stream_with_handle(streams::interval(HOLD_INTERVAL_MS, move || {
+                    to_msg(Msg::NavigationRepeated(forward))
+                })),
+            );
+        }
+        Msg::NavigationRepeated(forward) => {
+            let msg = if forward {
+                ViewMsg::Next
+            } else {
+                ViewMsg::Previous
+            };
+            if model.view.update(msg, &model.config, &model.options) == ViewChange::Unchanged {
+                model.hold_repeat = None;
+            }
+        }
+        Msg::NavigationReleased => {
+            model.hold_delay = None;
+            model.hold_repeat = None;
+        }
+        Msg::ShiftView { months, years } => {
+            update_view(ViewMsg::Shift { months, years }, model, orders, to_msg)
+        }
+        Msg::DialogTitleClicked if *model.options.zoom_on_double_click() => {}
+        Msg::DialogTitleClicked | Msg::ZoomOut => {
+            update_view(ViewMsg::ZoomOut, model, orders, to_msg)
+        }
+        Msg::ZoomIn => update_view(ViewMsg::ZoomIn, model, orders, to_msg),
+        Msg::SetViewType(new_dialog_type) => {
+            update_view(ViewMsg::SetViewType(new_dialog_type), model, orders, to_msg)
+        }
+        Msg::DialogTitleDoubleClicked if *model.options.zoom_on_double_click() => {
+            let msg = if model.view.dialog_view_type.larger_type().is_some() {
+                Msg::ZoomOut
+            } else {
+                Msg::SetViewType(*model.config.selection_type())
+            };
+            orders.send_msg(to_msg(msg));
+        }
+        Msg::DialogTitleDoubleClicked => {}
+        Msg::MonthPressed(month) => {
+            model.month_held = false;
+            model.month_hold_delay = Some(
+                orders.perform_cmd_with_handle(cmds::timeout(HOLD_DELAY_MS, move || {
+                    to_msg(Msg::MonthHeld(month))
+                })),
+            );
+        }
+        Msg::MonthHeld(month) => {
+            model.month_hold_delay = None;
+            model.month_held = true;
+            update_view(ViewMsg::MonthSelected(month), model, orders, to_msg);
+        }
+        Msg::MonthReleased => model.month_hold_delay = None,
+        Msg::ColorSchemeChanged => {
+            model.prefers_dark = model
+                .color_scheme_listener
+                .as_ref()
+                .is_some_and(ColorSchemeListener::prefers_dark);
+        }
+        Msg::FirstAllowedSelected(date) => {
+            model.view.viewed_date = date;
+            model.selection.set(date, *model.options.range_selection());
+            new_selection = Some(Some(date));
+        }
+        Msg::SetLoading(loading) => model.loading = loading,
+        Msg::ConfirmClicked if *model.options.range_selection() => {
+            if let Some(pending_range) = model.selection.confirm_range() {
+                close_on_select(model, orders, to_msg);
+                new_selection = Some(Some(pending_range.end()));
+            }
+        }
+        Msg::ConfirmClicked => {
+            if let Some(pending_date) = model.selection.confirm() {
+                close_on_select(model, orders, to_msg);
+                new_selection = Some(Some(pending_date));
+            }
+        }
+        Msg::CancelClicked => {
+            if let Some(selected_date) = model.selection.selected_date {
+                model.view.viewed_date = selected_date;
+            }
+            orders.send_msg(to_msg(Msg::CloseDialog));
+        }
+        Msg::DayHovered(hovered_date) => model.hovered_date = hovered_date,
+        Msg::SelectNextAllowed => {
+            if let Some(next_date) = model.next_allowed_date() {
+                orders.send_msg(to_msg(Msg::DateSelected(next_date)));
+            }
+        }
+        Msg::SelectPreviousAllowed => {
+            if let Some(previous_date) = model.previous_allowed_date() {
+                orders.send_msg(to_msg(Msg::DateSelected(previous_date)));
+            }
+        }
+        Msg::Undo => {
+            if model.selection.undo() {
+                new_selection = Some(view_restored_selection(model));
+            }
+        }
+        Msg::Redo => {
+            if model.selection.redo() {
+                new_selection = Some(view_restored_selection(model));
+            }
+        }
+    };
+    new_selection
+}
+
+/// `update` with an `on_change` message that doesn't carry the selected date,
+/// which has to be read from the `Model` instead
+pub fn update_with_msg<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    msg: Msg,
+    model: &mut Model<T>,
+    orders: &mut impl Orders<Ms>,
+    on_change: Ms,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+) {
+    update(msg, model, orders, |_| on_change, to_msg)
+}
 
-    /// whether the dialog is shown
-    dialog_opened: bool,
+/// `SelectionChange` describes a change of the selection, in the range mode the dates are the ends of the ranges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectionChange {
+    pub old: Option<NaiveDate>,
+    pub new: Option<NaiveDate>,
+}
 
-    /// viewed date
-    viewed_date: NaiveDate,
+/// `SelectionNotification` is sent with `orders.notify` on every selection change with the `notify_selection` option,
+/// so that any part of the app can react to it with `orders.subscribe` without routing the `on_change` message:
+///
+/// ```rust,ignore
+/// orders.subscribe(|notification: SelectionNotification| Msg::ReportDateChanged(notification.change.new));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectionNotification {
+    /// `id_prefix` option of the picker, to tell the pickers of the page apart
+    pub id_prefix: Option<String>,
+    pub change: SelectionChange,
+}
 
-    /// dialog type
-    dialog_view_type: DialogViewType,
+/// `update` with an `on_change` that receives both the previous and the new selection,
+/// e.g. for the undo features or the dirty-tracking of forms
+pub fn update_with_change<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    msg: Msg,
+    model: &mut Model<T>,
+    orders: &mut impl Orders<Ms>,
+    on_change: impl FnOnce(SelectionChange) -> Ms,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+) {
+    let old = model.settled_selection();
+    if update_model(msg, model, orders, to_msg).is_some() {
+        orders.send_msg(on_change(SelectionChange {
+            old,
+            new: model.reported_selection(),
+        }));
+    }
+}
 
-    /// dialog position style, describing the position of the dialog
-    dialog_position_style: Option<Style>,
+/// `update` for the `range_selection` mode, `on_change` receives the newly selected range
+/// instead of its end, `None` when the selection was cleared
+pub fn update_range<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    msg: Msg,
+    model: &mut Model<T>,
+    orders: &mut impl Orders<Ms>,
+    on_change: impl FnOnce(Option<DateRange>) -> Ms,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+) {
+    if update_model(msg, model, orders, to_msg).is_some() {
+        orders.send_msg(on_change(*model.selected_range()));
+    }
+}
 
-    /// configuration of the picker, should be passed in during init and not modified later
-    config: PickerConfig<T>,
+/// `update_range` reporting the start and the end of the newly selected range as another `PickerDate` type,
+/// a range that can't be represented by that type is reported as `None`
+pub fn update_range_as<
+    D: PickerDate,
+    Ms: 'static,
+    T: HasDateConstraints + std::default::Default + Clone,
+>(
+    msg: Msg,
+    model: &mut Model<T>,
+    orders: &mut impl Orders<Ms>,
+    on_change: impl FnOnce(Option<(D, D)>) -> Ms,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+) {
+    if update_model(msg, model, orders, to_msg).is_some() {
+        orders.send_msg(on_change(model.selected_range_as()));
+    }
 }
 
-impl<T: HasDateConstraints + Default + Clone> Model<T> {
-    /// selected value of the datepicker
-    pub fn selected_date(&self) -> &Option<NaiveDate> {
-        &self.selected_date
+/// sets the `new_date` as the selected date and closes the dialog if the `close_on_select` option is on
+fn select_date<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    new_date: NaiveDate,
+    model: &mut Model<T>,
+    orders: &mut impl Orders<Ms>,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+) {
+    model.selection.select(new_date);
+    close_on_select(model, orders, to_msg);
+}
+
+/// closes the dialog after a selection if the `close_on_select` option is on
+fn close_on_select<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    model: &Model<T>,
+    orders: &mut impl Orders<Ms>,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+) {
+    if *model.options.close_on_select() {
+        orders.send_msg(to_msg(Msg::CloseDialog));
     }
+}
 
-    pub fn config(&self) -> &PickerConfig<T> {
-        &self.config
+/// views the selection restored from the undo history, returns the newly reported selection
+fn view_restored_selection<T: HasDateConstraints + std::default::Default + Clone>(
+    model: &mut Model<T>,
+) -> Option<NaiveDate> {
+    if let Some(date) = model.reported_selection() {
+        model.view.viewed_date = date;
     }
+    model.reported_selection()
 }
 
-/// `init` describes what should happen when your app started.
-pub fn init<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
-    _: Url,
-    _: &mut impl Orders<Ms>,
-    config: PickerConfig<T>,
-    _to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
-) -> Model<T> {
-    Model {
-        selected_date: *config.initial_date(),
-        dialog_opened: *config.initially_opened(),
-        viewed_date: config.guess_allowed_year_month(),
-        dialog_view_type: *config.initial_view_type(),
-        dialog_position_style: None,
-        config,
+/// applies the `ViewMsg` to the `ViewState` of the `model`, the changed view type gets the keyboard focus
+/// and the selected month or year of the `selection_type` is selected
+fn update_view<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    msg: ViewMsg,
+    model: &mut Model<T>,
+    orders: &mut impl Orders<Ms>,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+) {
+    match model.view.update(msg, &model.config, &model.options) {
+        ViewChange::Zoomed => {
+            focus_after_render(model, orders);
+            reveal_selected_year(model, orders);
+        }
+        ViewChange::Selected(date) => {
+            orders.send_msg(to_msg(Msg::DateSelected(date)));
+        }
+        ViewChange::Navigated | ViewChange::Unchanged => {}
     }
 }
 
-/// `Msg` describes the different events you can modify state with.
-pub enum Msg {
-    DateSelected(NaiveDate),
-    MonthSelected(MonthNumber),
-    YearSelected(YearNumber),
-    /// open the dialog, optionally at the given (left, top) position
-    OpenDialog(Option<(String, String)>),
-    CloseDialog,
-    PreviousButtonClicked,
-    NextButtonClicked,
+/// moves the keyboard focus to the focus target cell after the next render, if `autofocus` is enabled
+fn focus_after_render<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    model: &Model<T>,
+    orders: &mut impl Orders<Ms>,
+) {
+    if *model.options.autofocus() {
+        focus_cell_after_render(model, orders);
+    }
+}
 
-    /// clicks on the dialog title change the `DialogViewType`
-    DialogTitleClicked,
+/// moves the keyboard focus to the focus target cell after the next render
+fn focus_cell_after_render<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    model: &Model<T>,
+    orders: &mut impl Orders<Ms>,
+) {
+    let focus_ref = model.focus_ref.clone();
+    orders.after_next_render(move |_| {
+        if let Some(cell) = focus_ref.get() {
+            cell.focus().ok();
+        }
+    });
 }
 
-/// `update` describes how to handle each `Msg`.
-pub fn update<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
-    msg: Msg,
+/// in the years view, displays the year group of the selected date and focuses its year cell,
+/// scrolled into the view, if the `reveal_selected_year` option is on
+fn reveal_selected_year<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
     model: &mut Model<T>,
     orders: &mut impl Orders<Ms>,
-    on_change: Ms,
-    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
 ) {
-    match msg {
-        Msg::DateSelected(new_date) => {
-            model.selected_date = Some(new_date);
-            model.viewed_date = new_date;
-            orders.send_msg(to_msg(Msg::CloseDialog));
-            orders.send_msg(on_change);
+    if !*model.options.reveal_selected_year()
+        || model.view.dialog_view_type != DialogViewType::Years
+    {
+        return;
+    }
+    if let Some(selection) = model.displayed_selection() {
+        let (first_day, last_day) = model.page_bounds(&model.view.viewed_date);
+        if !(first_day..=last_day).contains(&selection) {
+            model.view.viewed_date = selection;
         }
-        Msg::MonthSelected(new_month) => {
-            model.viewed_date = NaiveDate::from_ymd_opt(model.viewed_date.year(), new_month, 1)
-                .expect("Invalid date");
-            if model.config.selection_type() == &DialogViewType::Months {
-                orders.send_msg(to_msg(Msg::DateSelected(model.viewed_date)));
-            } else {
-                model.dialog_view_type = DialogViewType::Days;
-            }
+    }
+    let focus_ref = model.focus_ref.clone();
+    orders.after_next_render(move |_| {
+        if let Some(cell) = focus_ref.get() {
+            cell.focus().ok();
+            cell.scroll_into_view();
         }
-        Msg::YearSelected(new_year) => {
-            model.viewed_date = NaiveDate::from_ymd_opt(new_year, 1, 1).expect("Invalid date");
-            if model.config.selection_type() == &DialogViewType::Years {
-                orders.send_msg(to_msg(Msg::DateSelected(model.viewed_date)));
-            } else {
-                model.dialog_view_type = DialogViewType::Months;
+    });
+}
+
+/// moves the node of the `portal` option to the end of `document.body` after the next render,
+/// before the native dialog is shown, which would leave the top layer if it were moved while open
+fn mount_portal<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    model: &Model<T>,
+    orders: &mut impl Orders<Ms>,
+) {
+    if !*model.options.portal() {
+        return;
+    }
+    let portal_ref = model.portal_ref.clone();
+    orders.after_next_render(move |_| {
+        if let (Some(portal), Some(body)) = (portal_ref.get(), document().body()) {
+            if !body.is_same_node(portal.parent_node().as_ref()) {
+                body.append_child(&portal).ok();
             }
         }
-        Msg::OpenDialog(position) => {
-            model.dialog_opened = true;
-            if let Some((left, top)) = position {
-                model.dialog_position_style = Some(style! {
-                    St::Left => left,
-                    St::Top => top,
-                });
+    });
+}
+
+/// shows the native `<dialog>` or the popover after the next render, see the `dialog_element` option
+fn show_native_dialog<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    model: &Model<T>,
+    orders: &mut impl Orders<Ms>,
+) {
+    let dialog_element = *model.options.dialog_element();
+    let modal = *model.options.modal();
+    let root_ref = model.root_ref.clone();
+    orders.after_next_render(move |_| {
+        let root = match root_ref.get() {
+            Some(root) => root,
+            None => return,
+        };
+        match dialog_element {
+            DialogElement::Div => {}
+            DialogElement::Dialog => {
+                if let Some(dialog) = root.dyn_ref::<web_sys::HtmlDialogElement>() {
+                    if dialog.open() {
+                        return;
+                    }
+                    if modal {
+                        dialog.show_modal().ok();
+                    } else {
+                        dialog.show();
+                    }
+                }
             }
-        }
-        Msg::CloseDialog => model.dialog_opened = false,
-        Msg::PreviousButtonClicked => {
-            model.viewed_date = match model.dialog_view_type {
-                DialogViewType::Days => model.viewed_date.previous_month(),
-                DialogViewType::Months => model.viewed_date.previous_year(),
-                DialogViewType::Years => model.viewed_date.previous_year_group(),
-            };
-        }
-        Msg::NextButtonClicked => {
-            model.viewed_date = match model.dialog_view_type {
-                DialogViewType::Days => model.viewed_date.next_month(),
-                DialogViewType::Months => model.viewed_date.next_year(),
-                DialogViewType::Years => model.viewed_date.next_year_group(),
-            };
-        }
-        Msg::DialogTitleClicked => {
-            if let Some(new_dialog_type) = model.dialog_view_type.larger_type() {
-                model.dialog_view_type = new_dialog_type;
+            DialogElement::Popover => {
+                if !root.matches(":popover-open").unwrap_or(false) {
+                    root.show_popover().ok();
+                }
             }
         }
-    };
+    });
+}
+
+/// `Slots` contain the content of the app displayed inside of the dialog, e.g. its own buttons or notes.
+pub struct Slots<Ms> {
+    /// displayed between the header and the body
+    pub above_body: Vec<Node<Ms>>,
+    /// displayed between the body and the footer
+    pub below_body: Vec<Node<Ms>>,
+}
+
+impl<Ms> Default for Slots<Ms> {
+    fn default() -> Self {
+        Self {
+            above_body: Vec::new(),
+            below_body: Vec::new(),
+        }
+    }
 }
 
 /// `view` describes what to display.
@@ -151,13 +1574,144 @@ pub fn view<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
     model: &Model<T>,
     to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
 ) -> Node<Ms> {
-    IF!(model.dialog_opened => div![
-        C![DATEPICKER_ROOT],
-        model.dialog_position_style.as_ref(),
+    view_with_slots(model, to_msg, Slots::default())
+}
+
+/// `view` with the content of the app inside of the dialog
+pub fn view_with_slots<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    model: &Model<T>,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+    slots: Slots<Ms>,
+) -> Node<Ms> {
+    let dialog = if model.dialog_opened {
+        view_dialog(model, to_msg, slots)
+    } else {
+        empty![]
+    };
+    // Seed only patches the children of the portal moved to `document.body`,
+    // it's always rendered in a wrapper that stays where the `view` is called
+    let dialog = if *model.options.portal() {
+        div![C![PORTAL], el_ref(&model.portal_ref), dialog]
+    } else {
+        dialog
+    };
+    if *model.options.dom_events() {
+        div![
+            C![EVENT_TARGET],
+            el_ref(&model.events_ref),
+            style! { St::Display => "contents" },
+            dialog,
+        ]
+    } else if *model.options.portal() {
+        div![style! { St::Display => "contents" }, dialog]
+    } else {
+        dialog
+    }
+}
+
+/// the opened dialog with the backdrop of the `modal` option
+fn view_dialog<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    model: &Model<T>,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+    slots: Slots<Ms>,
+) -> Node<Ms> {
+    let dialog_element = *model.options.dialog_element();
+    let dialog = custom![
+        match dialog_element {
+            DialogElement::Dialog => Tag::Dialog,
+            DialogElement::Div | DialogElement::Popover => Tag::Div,
+        },
+        C![
+            DATEPICKER_ROOT,
+            IF!(model.is_dark() => DARK),
+            IF!(model.is_closing() => CLOSING),
+            IF!(model.options.anchor_name().is_some() => ANCHORED)
+        ],
+        el_ref(&model.root_ref),
+        IF!(dialog_element == DialogElement::Popover => attrs! { At::from("popover") => "auto" }),
+        match dialog_element {
+            DialogElement::Div => None,
+            // the `Escape` pressed outside of the key bindings
+            DialogElement::Dialog => Some(ev(Ev::from("cancel"), {
+                let to_msg = to_msg.clone();
+                move |event| {
+                    event.prevent_default();
+                    to_msg(Msg::CloseDialog)
+                }
+            })),
+            // the light dismiss of the browser
+            DialogElement::Popover => Some(ev(Ev::from("toggle"), {
+                let to_msg = to_msg.clone();
+                move |event| {
+                    let closed = event
+                        .target()
+                        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                        .is_some_and(|popover| !popover.matches(":popover-open").unwrap_or(true));
+                    IF!(closed => to_msg(Msg::CloseDialog))
+                }
+            })),
+        },
+        keyboard_ev(Ev::KeyDown, {
+            let key_bindings = model.options.key_bindings().clone();
+            let to_msg = to_msg.clone();
+            let day_typing =
+                *model.options.day_typing() && model.view.dialog_view_type == DialogViewType::Days;
+            move |event| {
+                let combination = KeyCombination::from_event(&event);
+                if key_bindings.action(&combination).is_some() {
+                    event.prevent_default();
+                    return Some(to_msg(Msg::KeyPressed(combination)));
+                }
+                let mut chars = combination.key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(digit), None)
+                        if day_typing
+                            && digit.is_ascii_digit()
+                            && !combination.ctrl
+                            && !combination.alt =>
+                    {
+                        Some(to_msg(Msg::DayDigitTyped(digit)))
+                    }
+                    _ => None,
+                }
+            }
+        }),
+        match model.options.anchor_name() {
+            Some(anchor_name) => Some(style! { St::from("position-anchor") => anchor_name }),
+            None => model.dialog_position_style.clone(),
+        },
         view_dialog_header(model, to_msg.clone()),
-        view_dialog_body(model, to_msg),
-    ])
-    .unwrap_or(empty![])
+        slots.above_body,
+        view_dialog_body(model, to_msg.clone()),
+        slots.below_body,
+        view_live_region(model),
+        IF!(model.view.dialog_view_type == DialogViewType::Days && !model.options.legend().is_empty() => view_legend(model)),
+        IF!(*model.options.confirm_selection() || *model.options.undo_buttons() => view_dialog_footer(model, to_msg.clone())),
+    ];
+    if *model.options.modal() && dialog_element == DialogElement::Div {
+        div![
+            div![C![BACKDROP], ev(Ev::Click, |_| to_msg(Msg::CloseDialog)),],
+            dialog,
+        ]
+    } else {
+        dialog
+    }
+}
+
+/// visually hidden text announced by the screen readers whenever the displayed period changes
+fn view_live_region<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    model: &Model<T>,
+) -> Node<Ms> {
+    span![
+        C![LIVE_REGION],
+        attrs! {
+            At::from("aria-live") => "polite",
+        },
+        match model.options.live_region_formatter() {
+            Some(live_region_formatter) => live_region_formatter(&model.title_text()),
+            None => format!("Showing {}", model.title_text()),
+        },
+    ]
 }
 
 fn view_dialog_header<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
@@ -166,91 +1720,209 @@ fn view_dialog_header<Ms: 'static, T: HasDateConstraints + std::default::Default
 ) -> Node<Ms> {
     div![
         C![HEADER],
-        button![
-            C![BUTTON, PREVIOUS],
-            style! {
-                St::Visibility => if should_display_previous_button(&model.dialog_view_type, &model.viewed_date, &model.config) { "visible" } else {"hidden"},
-            },
+        view_navigation_button(
+            model,
+            PREVIOUS,
             "«",
-            ev(Ev::Click, {
-                let to_msg = to_msg.clone();
-                |_| to_msg(Msg::PreviousButtonClicked)
-            }),
-        ],
+            model.can_navigate_previous(),
+            false,
+            to_msg.clone(),
+        ),
         span![
             C![TITLE],
+            model.element_id(TITLE).map(|id| attrs! { At::Id => id }),
             attrs! {
                 At::from("role") => "heading",
             },
-            create_dialog_title_text(
-                &model.dialog_view_type,
-                &model.viewed_date,
-                model.config.month_title_format()
-            ),
+            model.title_text(),
             ev(Ev::Click, {
                 let to_msg = to_msg.clone();
                 |_| to_msg(Msg::DialogTitleClicked)
             }),
-        ],
-        button![
-            C![BUTTON, NEXT],
-            style! {
-                St::Visibility => if should_display_next_button(&model.dialog_view_type, &model.viewed_date, &model.config) { "visible" } else { "hidden" },
-            },
-            "»",
-            ev(Ev::Click, {
+            ev(Ev::DblClick, {
                 let to_msg = to_msg.clone();
-                |_| to_msg(Msg::NextButtonClicked)
+                |_| to_msg(Msg::DialogTitleDoubleClicked)
             }),
         ],
+        view_navigation_button(
+            model,
+            NEXT,
+            "»",
+            model.can_navigate_next(),
+            true,
+            to_msg.clone(),
+        ),
         button![
             C![BUTTON, CLOSE],
+            model.element_id(CLOSE).map(|id| attrs! { At::Id => id }),
             "x",
             ev(Ev::Click, |_| to_msg(Msg::CloseDialog)),
         ],
     ]
 }
 
+/// previous or next (`forward`) button, the unavailable one is hidden or disabled according to
+/// the `unavailable_navigation`, holding the available one repeats the navigation
+fn view_navigation_button<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    model: &Model<T>,
+    class_name: &str,
+    label: &str,
+    available: bool,
+    forward: bool,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+) -> Node<Ms> {
+    let msg = if forward {
+        Msg::NextButtonClicked
+    } else {
+        Msg::PreviousButtonClicked
+    };
+    let disabled = *model.options.unavailable_navigation() == UnavailableNavigation::Disabled;
+    button![
+        C![BUTTON, class_name],
+        model
+            .element_id(class_name)
+            .map(|id| attrs! { At::Id => id }),
+        IF!(!available && !disabled => style! { St::Visibility => "hidden" }),
+        IF!(!available && disabled => attrs! {
+            At::Disabled => AtValue::None,
+            At::from("aria-disabled") => "true",
+        }),
+        label,
+        IF!(available => vec![
+            ev(Ev::PointerDown, {
+                let to_msg = to_msg.clone();
+                move |_| to_msg(Msg::NavigationPressed(forward))
+            }),
+            ev(Ev::PointerUp, {
+                let to_msg = to_msg.clone();
+                |_| to_msg(Msg::NavigationReleased)
+            }),
+            ev(Ev::PointerCancel, {
+                let to_msg = to_msg.clone();
+                |_| to_msg(Msg::NavigationReleased)
+            }),
+            ev(Ev::PointerLeave, {
+                let to_msg = to_msg.clone();
+                |_| to_msg(Msg::NavigationReleased)
+            }),
+            ev(Ev::Click, |_| to_msg(msg)),
+        ]),
+    ]
+}
+
+fn view_legend<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    model: &Model<T>,
+) -> Node<Ms> {
+    div![
+        C![LEGEND],
+        model
+            .options
+            .legend()
+            .iter()
+            .map(|rule| { span![span![C![SWATCH, rule.class_name()]], rule.to_string()] }),
+    ]
+}
+
+fn view_dialog_footer<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    model: &Model<T>,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+) -> Node<Ms> {
+    div![
+        C![FOOTER],
+        IF!(*model.options.undo_buttons() => vec![
+            button![
+                C![BUTTON, UNDO],
+                "Undo",
+                attrs! {
+                    At::Disabled => (!model.can_undo()).as_at_value(),
+                },
+                ev(Ev::Click, {
+                    let to_msg = to_msg.clone();
+                    |_| to_msg(Msg::Undo)
+                }),
+            ],
+            button![
+                C![BUTTON, REDO],
+                "Redo",
+                attrs! {
+                    At::Disabled => (!model.can_redo()).as_at_value(),
+                },
+                ev(Ev::Click, {
+                    let to_msg = to_msg.clone();
+                    |_| to_msg(Msg::Redo)
+                }),
+            ],
+        ]),
+        IF!(*model.options.confirm_selection() => vec![
+            button![
+                C![BUTTON, CANCEL],
+                "Cancel",
+                ev(Ev::Click, {
+                    let to_msg = to_msg.clone();
+                    |_| to_msg(Msg::CancelClicked)
+                }),
+            ],
+            button![
+                C![BUTTON, CONFIRM],
+                "OK",
+                attrs! {
+                    At::Disabled => (model.selection.pending_date.is_none()
+                        && model.selection.pending_range.is_none()).as_at_value(),
+                },
+                ev(Ev::Click, |_| to_msg(Msg::ConfirmClicked)),
+            ],
+        ]),
+    ]
+}
+
 fn view_dialog_body<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
     model: &Model<T>,
     to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
 ) -> Node<Ms> {
-    match model.dialog_view_type {
+    let mut body = match model.view.dialog_view_type {
+        DialogViewType::Days if model.is_week_strip() => view_week_strip(model, to_msg),
+        DialogViewType::Days if *model.options.days_layout() == DaysLayout::Agenda => {
+            view_agenda(model, to_msg)
+        }
         DialogViewType::Days => view_dialog_days(model, to_msg),
         DialogViewType::Months => view_dialog_months(model, to_msg),
         DialogViewType::Years => view_dialog_years(model, to_msg),
+    };
+    if model.loading {
+        if let Node::Element(body) = &mut body {
+            body.add_attr("aria-busy", "true");
+            body.add_child(div![C![LOADING_OVERLAY], span![C![SPINNER]]]);
+        }
     }
+    body
 }
 
 fn view_dialog_years<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
     model: &Model<T>,
     to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
 ) -> Node<Ms> {
-    let years: Vec<Node<Ms>> = year_group_range(model.viewed_date.year())
-        .map(|year| view_year_cell(year, model, to_msg.clone()))
+    let years: Vec<Node<Ms>> = model
+        .cells()
+        .into_iter()
+        .map(|cell| view_year_cell(cell, model, to_msg.clone()))
         .collect();
 
-    div![
-        C![BODY],
-        style! {
-            St::GridTemplateColumns => "1fr ".repeat(4),
-        },
-        years,
-    ]
+    div![C![BODY], view_grid_columns(model), years]
 }
 
 fn view_year_cell<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
-    year: i32,
+    cell: PageCell,
     model: &Model<T>,
     to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
 ) -> Node<Ms> {
-    let is_year_forbidden = model.config.is_year_forbidden(year);
-    let is_year_selected = model
-        .selected_date
-        .map_or(false, |optval| optval.year() == year);
+    let year = cell.date.year();
+    let is_year_forbidden = cell.is_forbidden;
+    let is_year_selected = cell.is_selected;
+    let is_focus_target = model.focus_target().year() == year;
+    let is_today = cell.is_today;
 
     span![
-        year.to_string(),
+        cell.label,
         C![
             if is_year_forbidden {
                 UNAVAILABLE
@@ -258,11 +1930,16 @@ fn view_year_cell<Ms: 'static, T: HasDateConstraints + std::default::Default + C
                 SELECTABLE
             },
             IF!(is_year_selected => SELECTED),
+            IF!(is_today => TODAY),
         ],
         attrs! {
             At::from("role") => "gridcell",
             At::AriaSelected => is_year_selected.as_at_value(),
+            At::TabIndex => if is_focus_target { "0" } else { "-1" },
         },
+        view_cell_state(is_year_selected, is_year_forbidden, false),
+        view_data_attrs(model, "year", year.to_string()),
+        IF!(is_focus_target => el_ref(&model.focus_ref)),
         IF!(!is_year_forbidden => ev(Ev::Click, move |_| to_msg(Msg::YearSelected(year)))),
     ]
 }
@@ -271,37 +1948,29 @@ fn view_dialog_months<Ms: 'static, T: HasDateConstraints + std::default::Default
     model: &Model<T>,
     to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
 ) -> Node<Ms> {
-    let months: Vec<Node<Ms>> = (1..=12u32)
-        .map(|month| {
-            view_month_cell(
-                NaiveDate::from_ymd_opt(model.viewed_date.year(), month, 1).expect("Invalid date"),
-                model,
-                to_msg.clone(),
-            )
-        })
+    let months: Vec<Node<Ms>> = model
+        .cells()
+        .into_iter()
+        .map(|cell| view_month_cell(cell, model, to_msg.clone()))
         .collect();
 
-    div![
-        C![BODY],
-        style! {
-            St::GridTemplateColumns => "1fr ".repeat(3),
-        },
-        months
-    ]
+    div![C![BODY], view_grid_columns(model), months]
 }
 
 fn view_month_cell<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
-    month_to_display: NaiveDate,
+    cell: PageCell,
     model: &Model<T>,
     to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
 ) -> Node<Ms> {
-    let is_month_forbidden = model.config.is_month_forbidden(&month_to_display);
-    let is_month_selected = model.selected_date.map_or(false, |optval| {
-        month_to_display.contains(&model.dialog_view_type, &optval)
-    });
+    let month_to_display = cell.date;
+    let is_month_forbidden = cell.is_forbidden;
+    let is_month_selected = cell.is_selected;
+    let is_focus_target = model.is_same_month(&model.focus_target(), &month_to_display);
+    let is_today = cell.is_today;
+    let month = model.calendar().calendar_date(&month_to_display).month;
 
     span![
-        Month::from_u32(month_to_display.month()).unwrap().name(),
+        cell.label,
         C![
             if is_month_forbidden {
                 UNAVAILABLE
@@ -309,48 +1978,168 @@ fn view_month_cell<Ms: 'static, T: HasDateConstraints + std::default::Default +
                 SELECTABLE
             },
             IF!(is_month_selected => SELECTED),
+            IF!(is_today => TODAY),
         ],
         attrs! {
             At::from("role") => "gridcell",
             At::AriaSelected => is_month_selected.as_at_value(),
+            At::TabIndex => if is_focus_target { "0" } else { "-1" },
         },
-        IF!(!is_month_forbidden => ev(Ev::Click, move |_| to_msg(Msg::MonthSelected(month_to_display.month())))),
+        view_cell_state(is_month_selected, is_month_forbidden, false),
+        view_data_attrs(model, "month", month_to_display.format("%Y-%m").to_string()),
+        IF!(is_focus_target => el_ref(&model.focus_ref)),
+        IF!(!is_month_forbidden && model.zooms_on_long_press() => vec![
+            ev(Ev::PointerDown, {
+                let to_msg = to_msg.clone();
+                move |_| to_msg(Msg::MonthPressed(month))
+            }),
+            ev(Ev::PointerUp, {
+                let to_msg = to_msg.clone();
+                |_| to_msg(Msg::MonthReleased)
+            }),
+            ev(Ev::PointerCancel, {
+                let to_msg = to_msg.clone();
+                |_| to_msg(Msg::MonthReleased)
+            }),
+            ev(Ev::PointerLeave, {
+                let to_msg = to_msg.clone();
+                |_| to_msg(Msg::MonthReleased)
+            }),
+        ]),
+        IF!(!is_month_forbidden => ev(Ev::Click, move |_| to_msg(Msg::MonthSelected(month)))),
+    ]
+}
+
+/// columns of the grid of the displayed cells
+fn view_grid_columns<T: HasDateConstraints + std::default::Default + Clone>(
+    model: &Model<T>,
+) -> Style {
+    style! {
+        St::GridTemplateColumns => "1fr ".repeat(model.view.grid_columns(&model.options)),
+    }
+}
+
+/// `data-state` attribute with the states of a cell and `aria-disabled` of the unavailable cells,
+/// so that the high-contrast stylesheets don't have to rely on the colors of the classes
+fn view_cell_state(selected: bool, disabled: bool, other_month: bool) -> Attrs {
+    let state: Vec<&str> = [
+        (selected, "selected"),
+        (disabled, "disabled"),
+        (other_month, "other-month"),
     ]
+    .iter()
+    .filter(|(is_in_state, _)| *is_in_state)
+    .map(|(_, state)| *state)
+    .collect();
+    attrs! {
+        At::from("data-state") => if state.is_empty() { AtValue::Ignored } else { AtValue::Some(state.join(" ")) },
+        At::from("aria-disabled") => if disabled { AtValue::Some("true".to_string()) } else { AtValue::Ignored },
+    }
+}
+
+/// `data-<kind>` attribute with the `value` of a cell, e.g. `data-date="2024-03-05"`,
+/// and its `id` prefixed with the `id_prefix` option, so that the tests can find it
+fn view_data_attrs<T: HasDateConstraints + std::default::Default + Clone>(
+    model: &Model<T>,
+    kind: &str,
+    value: String,
+) -> Attrs {
+    let mut data_attrs = Attrs::empty();
+    if let Some(id) = model.element_id(&value) {
+        data_attrs.add(At::Id, id);
+    }
+    data_attrs.add(At::from(format!("data-{}", kind)), value);
+    data_attrs
 }
 
 fn view_dialog_days<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
     model: &Model<T>,
     to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
 ) -> Node<Ms> {
-    let first_day_of_month = model.viewed_date.first_day_of_month();
-    let first_day_of_calendar = first_day_of_month
-        - Duration::days(first_day_of_month.weekday().num_days_from_monday().into());
-
-    let day_nodes: Vec<Node<Ms>> = first_day_of_calendar
-        .iter_days()
-        .take(7 * 6)
-        .map(|day| view_day_cell(day, model, to_msg.clone()))
+    let day_nodes: Vec<Node<Ms>> = model
+        .cells()
+        .into_iter()
+        .map(|cell| {
+            if cell.is_empty {
+                span![C![EMPTY_CELL], attrs! { At::from("role") => "gridcell" }]
+            } else {
+                view_day_cell(cell, model, to_msg.clone())
+            }
+        })
         .collect();
 
     div![
-        C!["body"],
-        style! {
-            St::GridTemplateColumns => "1fr ".repeat(7),
-        },
-        view_weekday_name(Weekday::Mon),
-        view_weekday_name(Weekday::Tue),
-        view_weekday_name(Weekday::Wed),
-        view_weekday_name(Weekday::Thu),
-        view_weekday_name(Weekday::Fri),
-        view_weekday_name(Weekday::Sat),
-        view_weekday_name(Weekday::Sun),
+        C![BODY],
+        view_grid_columns(model),
+        view_weekday_names(model),
         day_nodes,
     ]
 }
 
-fn view_weekday_name<Ms: 'static>(day: Weekday) -> Node<Ms> {
+/// the days view of the `DaysLayout::WeekStrip`, a single row with the week of the viewed date
+fn view_week_strip<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    model: &Model<T>,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+) -> Node<Ms> {
+    div![
+        C![BODY, WEEK_STRIP],
+        view_grid_columns(model),
+        view_weekday_names(model),
+        model
+            .cells()
+            .into_iter()
+            .map(|cell| view_day_cell(cell, model, to_msg.clone())),
+    ]
+}
+
+/// the days view of the `DaysLayout::Agenda`, a list of the selectable days of the viewed month
+fn view_agenda<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    model: &Model<T>,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+) -> Node<Ms> {
+    let days: Vec<Node<Ms>> = model
+        .cells()
+        .into_iter()
+        .filter(|cell| !cell.is_other_month && !cell.is_forbidden)
+        .map(|cell| {
+            div![
+                C![AGENDA_DAY],
+                span![
+                    C![GRID_HEADER],
+                    model
+                        .options
+                        .weekday_label_format()
+                        .label(cell.date.weekday())
+                ],
+                view_day_cell(cell, model, to_msg.clone()),
+            ]
+        })
+        .collect();
+    div![
+        C![BODY, AGENDA],
+        if days.is_empty() {
+            vec![span![
+                C![AGENDA_EMPTY],
+                model.options.agenda_empty_text().as_str()
+            ]]
+        } else {
+            days
+        },
+    ]
+}
+
+fn view_weekday_names<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
+    model: &Model<T>,
+) -> Vec<Node<Ms>> {
+    headless::weekday_labels(&model.options)
+        .into_iter()
+        .map(view_weekday_name)
+        .collect()
+}
+
+fn view_weekday_name<Ms: 'static>(label: String) -> Node<Ms> {
     span![
-        day.to_string(),
+        label,
         C![GRID_HEADER],
         attrs! {
             At::from("role") => "columnheader",
@@ -359,28 +2148,81 @@ fn view_weekday_name<Ms: 'static>(day: Weekday) -> Node<Ms> {
 }
 
 fn view_day_cell<Ms: 'static, T: HasDateConstraints + std::default::Default + Clone>(
-    date: NaiveDate,
+    cell: PageCell,
     model: &Model<T>,
     to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
 ) -> Node<Ms> {
-    let is_day_forbidden = model.config.is_day_forbidden(&date);
-    let is_date_selected = model.selected_date.map_or(false, |optval| optval == date);
+    let date = cell.date;
+    let is_day_forbidden = cell.is_forbidden;
+    let (forbidden_reason, forbidden_rule) = if is_day_forbidden {
+        (model.forbidden_reason(&date), model.forbidden_rule(&date))
+    } else {
+        (None, None)
+    };
+    let is_date_selected = cell.is_selected;
+    let is_focus_target = model.focus_target() == date;
+    let is_today = cell.is_today;
+    let is_other_month = cell.is_other_month;
+    let enter_selects_typed_day = model.enter_selects_typed_day();
+    let displayed_range = model.displayed_range();
+    let is_range_start = displayed_range.is_some_and(|range| range.start() == date);
+    let is_range_end = displayed_range.is_some_and(|range| range.end() == date);
+    let is_in_range = displayed_range
+        .is_some_and(|range| range.contains(&date) && !is_range_start && !is_range_end);
 
     span![
-        date.day().to_string(),
+        cell.label,
         C![
             if is_day_forbidden {
                 UNAVAILABLE
             } else {
                 SELECTABLE
             },
-            IF!(date.month() != model.viewed_date.month() => OTHER_MONTH),
+            IF!(is_other_month => OTHER_MONTH),
             IF!(is_date_selected => SELECTED),
+            IF!(is_today => TODAY),
+            IF!(is_range_start => RANGE_START),
+            IF!(is_range_end => RANGE_END),
+            IF!(is_in_range => IN_RANGE),
+            forbidden_rule.map(|rule| rule.class_name()),
         ],
         attrs! {
             At::from("role") => "gridcell",
-            At::AriaSelected => is_date_selected.as_at_value(),
+            At::AriaSelected => (is_date_selected || is_range_start || is_range_end).as_at_value(),
+            At::TabIndex => if is_focus_target { "0" } else { "-1" },
         },
-        IF!(!is_day_forbidden => ev(Ev::Click, move |_| to_msg(Msg::DateSelected(date)))),
+        view_cell_state(is_date_selected, is_day_forbidden, is_other_month),
+        view_data_attrs(model, "date", date.format("%Y-%m-%d").to_string()),
+        forbidden_reason.map(|reason| attrs! { At::Title => reason }),
+        IF!(is_focus_target => el_ref(&model.focus_ref)),
+        ev(Ev::MouseEnter, {
+            let to_msg = to_msg.clone();
+            move |_| to_msg(Msg::DayHovered(Some(date)))
+        }),
+        ev(Ev::MouseLeave, {
+            let to_msg = to_msg.clone();
+            |_| to_msg(Msg::DayHovered(None))
+        }),
+        IF!(!is_day_forbidden => keyboard_ev(Ev::KeyDown, {
+            let to_msg = to_msg.clone();
+            move |event| {
+                let msg = match event.key().as_str() {
+                    // handled by the key bindings of the dialog root
+                    "Enter" if enter_selects_typed_day => return None,
+                    "Enter" => Msg::DateEntered(date),
+                    " " => Msg::DateToggled(date),
+                    _ => return None,
+                };
+                // the key bindings of the dialog root must not handle it again
+                event.prevent_default();
+                event.stop_propagation();
+                Some(to_msg(msg))
+            }
+        })),
+        IF!(!is_day_forbidden => ev(Ev::Click, move |_| to_msg(if is_other_month {
+            Msg::OtherMonthDayClicked(date)
+        } else {
+            Msg::DateSelected(date)
+        }))),
     ]
 }
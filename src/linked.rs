@@ -0,0 +1,147 @@
+//! Two datepickers linked as a from/to pair, e.g. a departure and a return date.
+//!
+//! Selecting a date in the "from" picker makes it the earliest selectable date of the "to" picker
+//! and the "to" picker opens viewing that date, as long as nothing is selected in it yet.
+use seed::prelude::*;
+
+use crate::{
+    config::{date_constraints::DateConstraints, PickerConfig},
+    Model, Msg,
+};
+
+/// `LinkedPair` describes the state of both linked datepickers.
+pub struct LinkedPair {
+    from: Model<DateConstraints>,
+    to: Model<DateConstraints>,
+
+    /// constraints of the "to" picker before any date was selected in the "from" picker
+    to_constraints: DateConstraints,
+}
+
+impl LinkedPair {
+    pub fn from(&self) -> &Model<DateConstraints> {
+        &self.from
+    }
+
+    pub fn to(&self) -> &Model<DateConstraints> {
+        &self.to
+    }
+}
+
+/// `LinkedMsg` describes the events of the linked datepickers.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LinkedMsg {
+    From(Msg),
+    To(Msg),
+    /// a date was selected in the "from" picker
+    FromChanged,
+    /// a date was selected in the "to" picker
+    ToChanged,
+}
+
+/// `init` initializes both linked datepickers, each config is passed with the constraints it was built with,
+/// the `to_constraints` are restricted by the date selected in the "from" picker
+pub fn init<Ms: 'static>(
+    url: Url,
+    orders: &mut impl Orders<Ms>,
+    from_config: PickerConfig<DateConstraints>,
+    from_constraints: DateConstraints,
+    to_config: PickerConfig<DateConstraints>,
+    to_constraints: DateConstraints,
+    to_msg: impl FnOnce(LinkedMsg) -> Ms + Clone + 'static,
+) -> LinkedPair {
+    let mut pair = LinkedPair {
+        from: crate::init(url.clone(), orders, from_config, from_constraints, {
+            let to_msg = to_msg.clone();
+            move |msg| to_msg(LinkedMsg::From(msg))
+        }),
+        to: crate::init(url, orders, to_config, to_constraints.clone(), move |msg| {
+            to_msg(LinkedMsg::To(msg))
+        }),
+        to_constraints,
+    };
+    link(&mut pair);
+    pair
+}
+
+/// `update` handles the `LinkedMsg`s, `on_from_change` and `on_to_change` are sent when the selected date
+/// of the respective picker changes, a change in the "from" picker can also clear the selection of the "to" picker
+pub fn update<Ms: 'static>(
+    msg: LinkedMsg,
+    pair: &mut LinkedPair,
+    orders: &mut impl Orders<Ms>,
+    on_from_change: Ms,
+    on_to_change: Ms,
+    to_msg: impl FnOnce(LinkedMsg) -> Ms + Clone + 'static,
+) {
+    match msg {
+        LinkedMsg::From(msg) => crate::update(
+            msg,
+            &mut pair.from,
+            orders,
+            {
+                let to_msg = to_msg.clone();
+                |_| to_msg(LinkedMsg::FromChanged)
+            },
+            move |msg| to_msg(LinkedMsg::From(msg)),
+        ),
+        LinkedMsg::To(msg) => crate::update(
+            msg,
+            &mut pair.to,
+            orders,
+            {
+                let to_msg = to_msg.clone();
+                |_| to_msg(LinkedMsg::ToChanged)
+            },
+            move |msg| to_msg(LinkedMsg::To(msg)),
+        ),
+        LinkedMsg::FromChanged => {
+            orders.send_msg(on_from_change);
+            if link(pair) {
+                orders.send_msg(on_to_change);
+            }
+        }
+        LinkedMsg::ToChanged => {
+            orders.send_msg(on_to_change);
+        }
+    }
+}
+
+/// applies the selected date of the "from" picker to the "to" picker,
+/// returns whether the selected date of the "to" picker was cleared,
+/// the "to" picker keeps its constraints if the linked ones make its config invalid
+fn link(pair: &mut LinkedPair) -> bool {
+    let from_date = match pair.from.selection.selected_date {
+        Some(from_date) => from_date,
+        None => {
+            return pair
+                .to
+                .update_constraints(pair.to_constraints.clone())
+                .unwrap_or(false)
+        }
+    };
+    let cleared = pair
+        .to
+        .update_constraints(pair.to_constraints.with_later_min_date(from_date))
+        .unwrap_or(false);
+    if pair.to.selection.selected_date.is_none() {
+        pair.to.view.viewed_date = from_date;
+    }
+    cleared
+}
+
+/// `view_from` describes how to display the "from" picker.
+pub fn view_from<Ms: 'static>(
+    pair: &LinkedPair,
+    to_msg: impl FnOnce(LinkedMsg) -> Ms + Clone + 'static,
+) -> Node<Ms> {
+    crate::view(&pair.from, move |msg| to_msg(LinkedMsg::From(msg)))
+}
+
+/// `view_to` describes how to display the "to" picker.
+pub fn view_to<Ms: 'static>(
+    pair: &LinkedPair,
+    to_msg: impl FnOnce(LinkedMsg) -> Ms + Clone + 'static,
+) -> Node<Ms> {
+    crate::view(&pair.to, move |msg| to_msg(LinkedMsg::To(msg)))
+}
@@ -0,0 +1,228 @@
+use std::{collections::HashMap, ops::RangeInclusive};
+
+use chrono::prelude::*;
+use chrono_datepicker_core::{
+    config::{date_constraints::HasDateConstraints, PickerConfig},
+    dialog_view_type::DialogViewType,
+    viewed_date::YearNumber,
+};
+
+use crate::date::ymd_clamped;
+
+/// how many months in each direction are searched for a month with a selectable day
+const ALLOWED_MONTH_SEARCH_LIMIT: i32 = 100 * 12;
+
+/// how many days in each direction are searched for a selectable day
+const ALLOWED_DAY_SEARCH_LIMIT: usize = 100 * 366;
+
+/// number of years displayed by the years view, the same as in chrono-datepicker-core
+pub(crate) const YEARS_IN_YEAR_GROUP: YearNumber = 20;
+
+/// years of the year group containing the `year`, unlike the `year_group_range` of chrono-datepicker-core
+/// the groups of the negative years are aligned the same way as the positive ones, e.g. -20..=-1
+pub(crate) fn year_group_range(year: YearNumber) -> RangeInclusive<YearNumber> {
+    let first_year = year - year.rem_euclid(YEARS_IN_YEAR_GROUP);
+    first_year..=first_year + YEARS_IN_YEAR_GROUP - 1
+}
+
+/// first day of the fiscal year starting with the `fiscal_year_start` month, that contains the `date`
+pub(crate) fn fiscal_year_first_month(date: &NaiveDate, fiscal_year_start: Month) -> NaiveDate {
+    let start_month = fiscal_year_start.number_from_month();
+    let year = if date.month() >= start_month {
+        date.year()
+    } else {
+        date.year() - 1
+    };
+    ymd_clamped(year, start_month, 1)
+}
+
+/// first day of the month that is `months` months away from the month of the `date`
+pub(crate) fn month_offset(date: &NaiveDate, months: i32) -> Option<NaiveDate> {
+    let month_index = date.year() * 12 + date.month0() as i32 + months;
+    NaiveDate::from_ymd_opt(
+        month_index.div_euclid(12),
+        month_index.rem_euclid(12) as u32 + 1,
+        1,
+    )
+}
+
+/// month viewed when the picker is created, the one of the initial date or of `today`,
+/// moved to the nearest month with a selectable day, e.g. the month of the `min_date` if it is later than `today`
+pub(crate) fn initial_viewed_date<T: HasDateConstraints + Default + Clone>(
+    config: &PickerConfig<T>,
+    today: NaiveDate,
+) -> NaiveDate {
+    nearest_allowed_month(config, &config.initial_date().unwrap_or(today))
+}
+
+/// the `date` itself if its month contains a selectable day, otherwise the first day of the nearest such month,
+/// later months win ties, if there is no such month at all, the `date` is returned
+///
+/// The months of the forbidden years, e.g. the ones outside of the `min_date` and `max_date`,
+/// are skipped without checking their days. Beyond the search limit, the search continues
+/// from the nearest allowed year, so that e.g. a `min_date` centuries away is reached at once.
+pub(crate) fn nearest_allowed_month<C: HasDateConstraints>(
+    constraints: &C,
+    date: &NaiveDate,
+) -> NaiveDate {
+    search_allowed_month(constraints, date)
+        .or_else(|| {
+            let year = nearest_allowed_year(constraints, date.year())?;
+            let month = if year > date.year() { 1 } else { 12 };
+            search_allowed_month(constraints, &ymd_clamped(year, month, 1))
+        })
+        .unwrap_or(*date)
+}
+
+/// `nearest_allowed_month` within the search limit
+fn search_allowed_month<C: HasDateConstraints>(
+    constraints: &C,
+    date: &NaiveDate,
+) -> Option<NaiveDate> {
+    if !constraints.is_month_forbidden(date) {
+        return Some(*date);
+    }
+    let mut forbidden_years = HashMap::new();
+    (1..=ALLOWED_MONTH_SEARCH_LIMIT)
+        .flat_map(|offset| [offset, -offset])
+        .filter_map(|offset| month_offset(date, offset))
+        .find(|month| {
+            let year_forbidden = *forbidden_years
+                .entry(month.year())
+                .or_insert_with(|| constraints.is_year_forbidden(month.year()));
+            !year_forbidden && !constraints.is_month_forbidden(month)
+        })
+}
+
+/// the nearest year to the `year` that isn't forbidden, later years win ties
+fn nearest_allowed_year<C: HasDateConstraints>(
+    constraints: &C,
+    year: YearNumber,
+) -> Option<YearNumber> {
+    match (
+        first_allowed_year(constraints, year, 1),
+        first_allowed_year(constraints, year, -1),
+    ) {
+        (Some(later), Some(earlier)) if year - earlier < later - year => Some(earlier),
+        (None, earlier) => earlier,
+        (later, _) => later,
+    }
+}
+
+/// first year after the `year` in the `direction` (1 or -1) that isn't forbidden, found by doubling the distance
+/// and bisecting back, it is exact when the years stay allowed once they are, as with the `min_date` and `max_date`
+fn first_allowed_year<C: HasDateConstraints>(
+    constraints: &C,
+    year: YearNumber,
+    direction: i32,
+) -> Option<YearNumber> {
+    let is_allowed = |distance: i32| {
+        let year = year + direction * distance;
+        (NaiveDate::MIN.year()..=NaiveDate::MAX.year()).contains(&year)
+            && !constraints.is_year_forbidden(year)
+    };
+    let max_distance = NaiveDate::MAX.year() - NaiveDate::MIN.year();
+    let mut distance = 1;
+    while !is_allowed(distance) {
+        if distance > max_distance {
+            return None;
+        }
+        distance *= 2;
+    }
+    // the first allowed year is more than `forbidden` and at most `allowed` years away
+    let (mut forbidden, mut allowed) = (distance / 2, distance);
+    while allowed - forbidden > 1 {
+        let middle = forbidden + (allowed - forbidden) / 2;
+        if is_allowed(middle) {
+            allowed = middle;
+        } else {
+            forbidden = middle;
+        }
+    }
+    Some(year + direction * allowed)
+}
+
+/// the closest selectable day after the `date` if `forward`, otherwise before the `date`,
+/// `None` if there is no such day within the search limit
+pub(crate) fn nearest_allowed_day<C: HasDateConstraints>(
+    constraints: &C,
+    date: &NaiveDate,
+    forward: bool,
+) -> Option<NaiveDate> {
+    let step = |date: &NaiveDate| {
+        if forward {
+            date.succ_opt()
+        } else {
+            date.pred_opt()
+        }
+    };
+    std::iter::successors(step(date), step)
+        .take(ALLOWED_DAY_SEARCH_LIMIT)
+        .find(|day| !constraints.is_day_forbidden(day))
+}
+
+/// the first selectable day from the `date` on, the latest one before it if there is none, the forbidden years
+/// beyond the search limit, e.g. the ones before a `min_date` centuries away, are skipped at once
+pub(crate) fn first_allowed_day_from<C: HasDateConstraints>(
+    constraints: &C,
+    date: &NaiveDate,
+) -> Option<NaiveDate> {
+    allowed_day_towards(constraints, date, true)
+        .or_else(|| allowed_day_towards(constraints, date, false))
+}
+
+/// the `date` itself if it is selectable, otherwise the closest selectable day after it if `forward`,
+/// before it otherwise, searched from the first allowed year if there is none within the search limit
+fn allowed_day_towards<C: HasDateConstraints>(
+    constraints: &C,
+    date: &NaiveDate,
+    forward: bool,
+) -> Option<NaiveDate> {
+    let search = |date: &NaiveDate| {
+        Some(*date)
+            .filter(|date| !constraints.is_day_forbidden(date))
+            .or_else(|| nearest_allowed_day(constraints, date, forward))
+    };
+    search(date).or_else(|| {
+        let direction = if forward { 1 } else { -1 };
+        let year = first_allowed_year(constraints, date.year(), direction)?;
+        let (month, day) = if forward { (1, 1) } else { (12, 31) };
+        search(&ymd_clamped(year, month, day))
+    })
+}
+
+/// first and last day displayed in the dialog of the `DialogViewType` viewing the `viewed_date`
+pub(crate) fn page_bounds(
+    dialog_view_type: &DialogViewType,
+    viewed_date: &NaiveDate,
+    fiscal_year_start: Option<Month>,
+) -> (NaiveDate, NaiveDate) {
+    if let (DialogViewType::Months, Some(fiscal_year_start)) = (dialog_view_type, fiscal_year_start)
+    {
+        let first_day = fiscal_year_first_month(viewed_date, fiscal_year_start);
+        let last_day = month_offset(&first_day, 12)
+            .and_then(|next_year| next_year.pred_opt())
+            .unwrap_or(NaiveDate::MAX);
+        return (first_day, last_day);
+    }
+    let (first_year, last_year) = match dialog_view_type {
+        DialogViewType::Years => {
+            let years = year_group_range(viewed_date.year());
+            (*years.start(), *years.end())
+        }
+        _ => (viewed_date.year(), viewed_date.year()),
+    };
+    match dialog_view_type {
+        DialogViewType::Days => {
+            let first_day = ymd_clamped(viewed_date.year(), viewed_date.month(), 1);
+            let last_day = month_offset(&first_day, 1)
+                .and_then(|next_month| next_month.pred_opt())
+                .unwrap_or(NaiveDate::MAX);
+            (first_day, last_day)
+        }
+        _ => (
+            ymd_clamped(first_year, 1, 1),
+            ymd_clamped(last_year, 12, 31),
+        ),
+    }
+}
@@ -0,0 +1,728 @@
+use std::{fmt, rc::Rc, time::Duration};
+
+use chrono::{Datelike, FixedOffset, Local, Month, NaiveDate, Utc, Weekday};
+use chrono_datepicker_core::dialog_view_type::DialogViewType;
+use derive_builder::Builder;
+use getset::Getters;
+
+use crate::{
+    calendar::CalendarSystem,
+    config::date_constraints::{CheckDateConstraints, ConstraintRule},
+    coordinator::OpenCoordinator,
+    format::ValueFormat,
+    keyboard::KeyBindings,
+    year_display::YearDisplay,
+    InteractionEvent,
+};
+
+/// function returning the reason why a date can't be selected, `None` if there is no reason to display
+pub type ForbiddenReasonFn = Rc<dyn Fn(&NaiveDate) -> Option<String>>;
+
+/// function returning the category of the rule that forbids a date, `None` if it has no category to display
+pub type ForbiddenRuleFn = Rc<dyn Fn(&NaiveDate) -> Option<ConstraintRule>>;
+
+/// function called with every `InteractionEvent` of the dialog
+pub type InteractionFn = Rc<dyn Fn(InteractionEvent)>;
+
+/// function returning the title of the dialog of the given `DialogViewType` viewing the given date
+pub type TitleFormatterFn = Rc<dyn Fn(DialogViewType, NaiveDate) -> String>;
+
+/// function returning the text announced by the screen readers when the dialog displays the period of the given title
+pub type LiveRegionFn = Rc<dyn Fn(&str) -> String>;
+
+/// function returning the current date, e.g. of the timezone the app operates in
+pub type TodayFn = Rc<dyn Fn() -> NaiveDate>;
+
+/// `PickerOptions` describes the behavior of the Seed dialog itself,
+/// everything that concerns the dates is configured in the [`PickerConfig`](crate::config::PickerConfig).
+#[derive(Clone, Builder, Getters)]
+#[builder(default, build_fn(validate = "Self::validate"))]
+#[getset(get = "pub")]
+pub struct PickerOptions {
+    /// move the keyboard focus to the cell of the selected/viewed period after the `DialogViewType` changes
+    autofocus: bool,
+
+    /// keyboard shortcuts of the dialog, `KeyBindings::empty()` disables them
+    key_bindings: KeyBindings,
+
+    /// whether typing the digits of a day number in the days view moves the focus to that day,
+    /// the digits typed within a second make up one number
+    day_typing: bool,
+
+    /// color scheme of the dialog, the `Dark` one adds the `dark` class to the datepicker root
+    color_scheme: ColorScheme,
+
+    /// whether a backdrop covers the page behind the dialog, blocking it and closing the dialog when clicked
+    modal: bool,
+
+    /// whether the dialog opened with `Msg::OpenDialogAt` is positioned relative to the viewport (`position: fixed`),
+    /// so that the ancestors with `overflow: hidden` don't clip it; an ancestor with a `transform` or a `filter`
+    /// still contains it, see the `portal` option
+    fixed_position: bool,
+
+    /// whether the dialog is rendered into a portal node appended to `document.body` when it opens,
+    /// so that no ancestor of the `view` clips or contains it; the `view` leaves an empty placeholder in its place
+    portal: bool,
+
+    /// where the dialog opened with `Msg::OpenDialogAt` is placed relative to its anchor
+    placement: Placement,
+
+    /// whether the dialog opened with `Msg::OpenDialogAt` follows its anchor when the page is scrolled or resized
+    follow_anchor: bool,
+
+    /// CSS `anchor-name` of the element the dialog is positioned at, e.g. `--checkin-input`,
+    /// the dialog gets the `position-anchor` and the `anchored` class instead of the computed position,
+    /// so that the placement is left to the CSS anchor positioning of the browser
+    #[builder(setter(into, strip_option))]
+    anchor_name: Option<String>,
+
+    /// element the dialog is rendered as, the native `<dialog>` and the popover get the focus trapping,
+    /// the `Escape` handling and the stacking above the rest of the page from the browser
+    dialog_element: DialogElement,
+
+    /// whether the dialog gets closed after a date is selected
+    close_on_select: bool,
+
+    /// how long the dialog stays rendered with the `closing` class after it gets closed,
+    /// so that it can be animated by the CSS
+    #[builder(setter(strip_option))]
+    closing_duration: Option<Duration>,
+
+    /// closes the dialog after it wasn't used for the given duration, e.g. in kiosks
+    #[builder(setter(strip_option))]
+    auto_close_after: Option<Duration>,
+
+    /// the `on_change` is only sent once no other selection change followed for the given duration,
+    /// e.g. to refetch the data once after the keyboard navigation
+    #[builder(setter(strip_option))]
+    change_debounce: Option<Duration>,
+
+    /// whether the cells of the current day, month and year get the `today` class, apps that compute
+    /// the current date in another timezone can turn it off and highlight it themselves
+    highlight_today: bool,
+
+    /// current date used instead of the one of the local timezone, e.g. for deterministic rendering in tests
+    #[builder(setter(custom))]
+    today_provider: Option<TodayFn>,
+
+    /// timezone of the current date when there is no `today_provider`
+    today_timezone: TodayTimezone,
+
+    /// century of the typed dates with a two-digit year, e.g. `05.03.24`, see the `parse` module
+    two_digit_year: TwoDigitYear,
+
+    /// shared by the pickers of which only one dialog can be open at a time
+    #[builder(setter(strip_option))]
+    open_coordinator: Option<OpenCoordinator>,
+
+    /// whether the years view displays the year group of the selected date when it appears
+    /// and focuses the year cell of the selected date, scrolled into the view
+    reveal_selected_year: bool,
+
+    /// whether the title switches the `DialogViewType` on a double-click instead of a single click,
+    /// it zooms out until the years view, where it returns to the `selection_type` of the config
+    zoom_on_double_click: bool,
+
+    /// whether holding a month cell displays the days of the month, even before the pointer is released
+    zoom_on_long_press: bool,
+
+    /// whether a selected date, or a completed range in the `range_selection` mode, only gets staged
+    /// until the OK button in the footer is clicked
+    confirm_selection: bool,
+
+    /// whether the footer displays the buttons sending `Msg::Undo` and `Msg::Redo`
+    undo_buttons: bool,
+
+    /// whether selecting a year or a month while drilling down keeps the viewed month and day,
+    /// otherwise the viewed date is reset to the January 1st / 1st day of the month
+    preserve_viewed_date: bool,
+
+    /// whether two clicked days select a range instead of a single date
+    range_selection: bool,
+
+    /// whether `init` selects the first selectable day from today on (the latest one before today
+    /// if there is none) when the config has no `initial_date`, the `on_change` of `update` is called with it
+    auto_select_first_allowed: bool,
+
+    /// LocalStorage key of the last selected date, which `init` restores as the `initial_date`
+    /// when the config has none and the stored date is allowed, in the `range_selection` mode
+    /// the whole last selected range is stored and restored if none of its ends is forbidden
+    #[builder(setter(into, strip_option))]
+    persistence_key: Option<String>,
+
+    /// minimal number of days between the start and the end of a selected range
+    #[builder(setter(strip_option))]
+    min_range_length: Option<u32>,
+
+    /// maximal number of days between the start and the end of a selected range
+    #[builder(setter(strip_option))]
+    max_range_length: Option<u32>,
+
+    /// whether a selected range may contain forbidden days, otherwise the days beyond
+    /// the first forbidden day on either side of the range start can't be picked as its end
+    allow_forbidden_in_range: bool,
+
+    /// first month of the fiscal year, the `DialogViewType::Months` then displays and titles
+    /// a whole fiscal year, e.g. `FY2024: Apr 2024 – Mar 2025`
+    #[builder(setter(strip_option))]
+    fiscal_year_start: Option<Month>,
+
+    /// earliest date the previous button can navigate to, without it the previous button is displayed
+    /// as long as the previous period contains a selectable date
+    #[builder(setter(strip_option))]
+    navigation_min: Option<NaiveDate>,
+
+    /// latest date the next button can navigate to, without it the next button is displayed
+    /// as long as the next period contains a selectable date
+    #[builder(setter(strip_option))]
+    navigation_max: Option<NaiveDate>,
+
+    /// how the previous/next buttons are rendered when they can't be used
+    unavailable_navigation: UnavailableNavigation,
+
+    /// prefix of the `id`s of the buttons and the cells of the dialog, e.g. `checkin-2024-03-05`,
+    /// it has to be unique if the page contains more datepickers
+    #[builder(setter(into, strip_option))]
+    id_prefix: Option<String>,
+
+    /// how the days view displays the days, the month grid by default
+    days_layout: DaysLayout,
+
+    /// text of the `DaysLayout::Agenda` of a month without a selectable day
+    #[builder(setter(into))]
+    agenda_empty_text: String,
+
+    /// number of columns of the years view, 4 by default, the builder fails with 0
+    year_grid_columns: u8,
+
+    /// number of columns of the months view, 3 by default, the builder fails with 0
+    month_grid_columns: u8,
+
+    /// whether the days view leaves out the trailing weeks consisting only of the days of the next month,
+    /// otherwise it always displays 6 weeks, so that its height doesn't change
+    trim_trailing_weeks: bool,
+
+    /// whether the days of the previous and the next month are displayed in the days view,
+    /// otherwise their cells stay empty
+    show_other_month_days: bool,
+
+    /// what clicking a day of the previous or the next month in the days view does
+    other_month_click: OtherMonthClick,
+
+    /// what the headers of the day grid display for each weekday
+    weekday_label_format: WeekdayLabelFormat,
+
+    /// what the cells of the day grid display
+    day_label_format: DayLabelFormat,
+
+    /// what the cells of the months view display
+    month_label_format: MonthLabelFormat,
+
+    /// reason why a date can't be selected, displayed as the `title` of the unavailable day cells
+    #[builder(setter(custom))]
+    forbidden_reason: Option<ForbiddenReasonFn>,
+
+    /// category of the rule that forbids a date, its class is added to the unavailable day cells,
+    /// set together with the `legend` by `PickerOptionsBuilder::constraint_rules`
+    #[builder(setter(custom))]
+    forbidden_rule: Option<ForbiddenRuleFn>,
+
+    /// rules listed with their swatches below the days view, set by `PickerOptionsBuilder::constraint_rules`
+    #[builder(setter(custom))]
+    legend: Vec<ConstraintRule>,
+
+    /// format of the selection returned by `Model::formatted_value`, `%Y-%m-%d` by default,
+    /// the builder fails if it can't format a date
+    #[builder(setter(into))]
+    value_format: ValueFormat,
+
+    /// replaces the default title of the dialog, e.g. for the locales where "%b %Y" isn't adequate
+    #[builder(setter(custom))]
+    title_formatter: Option<TitleFormatterFn>,
+
+    /// replaces the English "Showing {title}" announced by the screen readers when the displayed period changes
+    #[builder(setter(custom))]
+    live_region_formatter: Option<LiveRegionFn>,
+
+    /// how the year numbers are displayed in the years view and in the title, e.g. `JapaneseEra`
+    #[builder(setter(custom))]
+    year_display: Option<Rc<dyn YearDisplay>>,
+
+    /// called with every `InteractionEvent`, e.g. to wire the product analytics in one place
+    #[builder(setter(custom))]
+    on_interaction: Option<InteractionFn>,
+
+    /// whether the `datepicker-open`, `datepicker-change` and `datepicker-close` DOM `CustomEvent`s
+    /// with the ISO date of the selection in the `detail` are dispatched from an element wrapping the dialog,
+    /// see `dom_events`
+    dom_events: bool,
+
+    /// whether every selection change is also sent with `orders.notify` as a `SelectionNotification`
+    notify_selection: bool,
+
+    /// calendar system of the days and months views, the Gregorian one by default,
+    /// the years view and the `fiscal_year_start` stay Gregorian
+    #[builder(setter(custom))]
+    calendar_system: Option<Rc<dyn CalendarSystem>>,
+}
+
+impl Default for PickerOptions {
+    fn default() -> Self {
+        Self {
+            autofocus: false,
+            key_bindings: KeyBindings::default(),
+            day_typing: false,
+            color_scheme: ColorScheme::default(),
+            modal: false,
+            fixed_position: false,
+            portal: false,
+            placement: Placement::default(),
+            follow_anchor: false,
+            anchor_name: None,
+            dialog_element: DialogElement::default(),
+            close_on_select: true,
+            closing_duration: None,
+            auto_close_after: None,
+            change_debounce: None,
+            highlight_today: true,
+            today_provider: None,
+            today_timezone: TodayTimezone::default(),
+            two_digit_year: TwoDigitYear::default(),
+            open_coordinator: None,
+            reveal_selected_year: false,
+            zoom_on_double_click: false,
+            zoom_on_long_press: false,
+            confirm_selection: false,
+            undo_buttons: false,
+            preserve_viewed_date: true,
+            range_selection: false,
+            auto_select_first_allowed: false,
+            persistence_key: None,
+            min_range_length: None,
+            max_range_length: None,
+            allow_forbidden_in_range: true,
+            fiscal_year_start: None,
+            navigation_min: None,
+            navigation_max: None,
+            unavailable_navigation: UnavailableNavigation::default(),
+            id_prefix: None,
+            days_layout: DaysLayout::default(),
+            agenda_empty_text: "No available days".to_owned(),
+            year_grid_columns: 4,
+            month_grid_columns: 3,
+            trim_trailing_weeks: false,
+            show_other_month_days: true,
+            other_month_click: OtherMonthClick::default(),
+            weekday_label_format: WeekdayLabelFormat::default(),
+            day_label_format: DayLabelFormat::default(),
+            month_label_format: MonthLabelFormat::default(),
+            forbidden_reason: None,
+            forbidden_rule: None,
+            legend: Vec::new(),
+            value_format: ValueFormat::default(),
+            title_formatter: None,
+            live_region_formatter: None,
+            year_display: None,
+            on_interaction: None,
+            dom_events: false,
+            notify_selection: false,
+            calendar_system: None,
+        }
+    }
+}
+
+impl fmt::Debug for PickerOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PickerOptions")
+            .field("autofocus", &self.autofocus)
+            .field("key_bindings", &self.key_bindings)
+            .field("day_typing", &self.day_typing)
+            .field("color_scheme", &self.color_scheme)
+            .field("modal", &self.modal)
+            .field("fixed_position", &self.fixed_position)
+            .field("portal", &self.portal)
+            .field("placement", &self.placement)
+            .field("follow_anchor", &self.follow_anchor)
+            .field("anchor_name", &self.anchor_name)
+            .field("dialog_element", &self.dialog_element)
+            .field("close_on_select", &self.close_on_select)
+            .field("closing_duration", &self.closing_duration)
+            .field("auto_close_after", &self.auto_close_after)
+            .field("change_debounce", &self.change_debounce)
+            .field("highlight_today", &self.highlight_today)
+            .field("today_provider", &self.today_provider.is_some())
+            .field("today_timezone", &self.today_timezone)
+            .field("two_digit_year", &self.two_digit_year)
+            .field("open_coordinator", &self.open_coordinator)
+            .field("reveal_selected_year", &self.reveal_selected_year)
+            .field("zoom_on_double_click", &self.zoom_on_double_click)
+            .field("zoom_on_long_press", &self.zoom_on_long_press)
+            .field("confirm_selection", &self.confirm_selection)
+            .field("undo_buttons", &self.undo_buttons)
+            .field("preserve_viewed_date", &self.preserve_viewed_date)
+            .field("range_selection", &self.range_selection)
+            .field("auto_select_first_allowed", &self.auto_select_first_allowed)
+            .field("persistence_key", &self.persistence_key)
+            .field("min_range_length", &self.min_range_length)
+            .field("max_range_length", &self.max_range_length)
+            .field("allow_forbidden_in_range", &self.allow_forbidden_in_range)
+            .field("fiscal_year_start", &self.fiscal_year_start)
+            .field("navigation_min", &self.navigation_min)
+            .field("navigation_max", &self.navigation_max)
+            .field("unavailable_navigation", &self.unavailable_navigation)
+            .field("id_prefix", &self.id_prefix)
+            .field("days_layout", &self.days_layout)
+            .field("agenda_empty_text", &self.agenda_empty_text)
+            .field("year_grid_columns", &self.year_grid_columns)
+            .field("month_grid_columns", &self.month_grid_columns)
+            .field("trim_trailing_weeks", &self.trim_trailing_weeks)
+            .field("show_other_month_days", &self.show_other_month_days)
+            .field("other_month_click", &self.other_month_click)
+            .field("weekday_label_format", &self.weekday_label_format)
+            .field("day_label_format", &self.day_label_format)
+            .field("month_label_format", &self.month_label_format)
+            .field("forbidden_reason", &self.forbidden_reason.is_some())
+            .field("forbidden_rule", &self.forbidden_rule.is_some())
+            .field("legend", &self.legend)
+            .field("value_format", &self.value_format)
+            .field("title_formatter", &self.title_formatter.is_some())
+            .field(
+                "live_region_formatter",
+                &self.live_region_formatter.is_some(),
+            )
+            .field("year_display", &self.year_display.is_some())
+            .field("on_interaction", &self.on_interaction.is_some())
+            .field("dom_events", &self.dom_events)
+            .field("notify_selection", &self.notify_selection)
+            .field("calendar_system", &self.calendar_system.is_some())
+            .finish()
+    }
+}
+
+impl PickerOptions {
+    /// current date of the `today_provider`, or in the `today_timezone` if there is none
+    pub fn today(&self) -> NaiveDate {
+        self.today_provider
+            .as_ref()
+            .map_or_else(|| self.today_timezone.today(), |today| today())
+    }
+}
+
+impl PickerOptionsBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(value_format) = self
+            .value_format
+            .as_ref()
+            .filter(|format| !format.is_valid())
+        {
+            return Err(format!(
+                "value_format {:?} can't format a date",
+                value_format
+            ));
+        }
+        if self.year_grid_columns == Some(0) {
+            return Err("year_grid_columns must be at least 1".to_owned());
+        }
+        if self.month_grid_columns == Some(0) {
+            return Err("month_grid_columns must be at least 1".to_owned());
+        }
+        Ok(())
+    }
+
+    pub fn today_provider(
+        &mut self,
+        today_provider: impl Fn() -> NaiveDate + 'static,
+    ) -> &mut Self {
+        self.today_provider = Some(Some(Rc::new(today_provider)));
+        self
+    }
+
+    pub fn forbidden_reason(
+        &mut self,
+        forbidden_reason: impl Fn(&NaiveDate) -> Option<String> + 'static,
+    ) -> &mut Self {
+        self.forbidden_reason = Some(Some(Rc::new(forbidden_reason)));
+        self
+    }
+
+    pub fn forbidden_rule(
+        &mut self,
+        forbidden_rule: impl Fn(&NaiveDate) -> Option<ConstraintRule> + 'static,
+    ) -> &mut Self {
+        self.forbidden_rule = Some(Some(Rc::new(forbidden_rule)));
+        self
+    }
+
+    /// lists the active rules of the `constraints` in the legend and adds the class of the rule
+    /// that forbids a day to its cell, so that both come from the same constraints
+    pub fn constraint_rules<C: CheckDateConstraints + 'static>(
+        &mut self,
+        constraints: C,
+    ) -> &mut Self {
+        self.legend = Some(constraints.active_rules());
+        self.forbidden_rule(move |date| {
+            constraints
+                .check_day(date)
+                .err()
+                .and_then(|rejection| rejection.rule())
+        })
+    }
+
+    pub fn title_formatter(
+        &mut self,
+        title_formatter: impl Fn(DialogViewType, NaiveDate) -> String + 'static,
+    ) -> &mut Self {
+        self.title_formatter = Some(Some(Rc::new(title_formatter)));
+        self
+    }
+
+    pub fn live_region_formatter(
+        &mut self,
+        live_region_formatter: impl Fn(&str) -> String + 'static,
+    ) -> &mut Self {
+        self.live_region_formatter = Some(Some(Rc::new(live_region_formatter)));
+        self
+    }
+
+    pub fn year_display(&mut self, year_display: impl YearDisplay + 'static) -> &mut Self {
+        self.year_display = Some(Some(Rc::new(year_display)));
+        self
+    }
+
+    pub fn on_interaction(
+        &mut self,
+        on_interaction: impl Fn(InteractionEvent) + 'static,
+    ) -> &mut Self {
+        self.on_interaction = Some(Some(Rc::new(on_interaction)));
+        self
+    }
+
+    pub fn calendar_system(&mut self, calendar_system: impl CalendarSystem + 'static) -> &mut Self {
+        self.calendar_system = Some(Some(Rc::new(calendar_system)));
+        self
+    }
+}
+
+/// `ColorScheme` of the dialog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorScheme {
+    #[default]
+    Light,
+    Dark,
+    /// follows the `prefers-color-scheme` media query of the browser
+    Auto,
+}
+
+/// `UnavailableNavigation` describes the previous/next buttons that can't be used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnavailableNavigation {
+    /// the buttons keep their place in the layout, but are invisible
+    #[default]
+    Hidden,
+    /// the buttons are visible with the `disabled` and `aria-disabled` attributes
+    Disabled,
+}
+
+/// `DaysLayout` describes how the days view displays the days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DaysLayout {
+    /// weeks of the viewed month in a grid
+    #[default]
+    Grid,
+    /// only the week of the viewed date in a single row, the previous/next buttons navigate by weeks
+    WeekStrip,
+    /// list of the selectable days of the viewed month with their weekdays in the `weekday_label_format`,
+    /// e.g. for very narrow layouts
+    Agenda,
+}
+
+/// `OtherMonthClick` describes what clicking a day of the previous or the next month in the days view does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OtherMonthClick {
+    /// selects the day as any other one, which also displays its month if the dialog stays open
+    #[default]
+    Select,
+    /// only displays the month of the day
+    Navigate,
+}
+
+/// `DialogElement` the datepicker root is rendered as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DialogElement {
+    /// plain `<div>` positioned by the datepicker or the CSS
+    #[default]
+    Div,
+    /// native `<dialog>` shown with `showModal()` in the `modal` mode, otherwise with `show()`,
+    /// the modal one is rendered in the top layer, which no ancestor clips
+    Dialog,
+    /// `<div>` with the `popover` attribute shown with `showPopover()` in the top layer, closed by the browser
+    /// when the user clicks outside of it
+    Popover,
+}
+
+/// `Placement` of the dialog relative to the anchor it was opened at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Placement {
+    /// below the anchor, aligned with its left edge
+    #[default]
+    BelowStart,
+    /// below the anchor, aligned with its right edge
+    BelowEnd,
+    /// above the anchor, aligned with its left edge
+    Above,
+    /// below the start, flipped above and/or to the end if the dialog doesn't fit into the viewport
+    Auto,
+}
+
+/// `TwoDigitYear` describes the century of a year typed with only its last two digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TwoDigitYear {
+    /// the years below the pivot are in the 2000s, the other ones in the 1900s,
+    /// e.g. `Pivot(50)` reads 00–49 as 2000–2049 and 50–99 as 1950–1999
+    Pivot(u8),
+    /// the year closest to the current one, e.g. in 2024 `30` is 2030, but `80` is 1980
+    ClosestToToday,
+}
+
+impl TwoDigitYear {
+    /// full year of the last two digits `year_of_century` typed on the `today` date
+    pub fn full_year(&self, year_of_century: u8, today: NaiveDate) -> i32 {
+        let year_of_century = i32::from(year_of_century % 100);
+        match self {
+            TwoDigitYear::Pivot(pivot) if year_of_century < i32::from(*pivot) => {
+                2000 + year_of_century
+            }
+            TwoDigitYear::Pivot(_) => 1900 + year_of_century,
+            TwoDigitYear::ClosestToToday => {
+                let century = today.year() - today.year().rem_euclid(100);
+                [century - 100, century, century + 100]
+                    .iter()
+                    .map(|century| century + year_of_century)
+                    .min_by_key(|year| (year - today.year()).abs())
+                    .expect("Candidate years are not empty")
+            }
+        }
+    }
+}
+
+impl Default for TwoDigitYear {
+    fn default() -> Self {
+        TwoDigitYear::Pivot(50)
+    }
+}
+
+/// `TodayTimezone` in which the current date is computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TodayTimezone {
+    /// timezone of the browser
+    #[default]
+    Local,
+    Utc,
+    /// fixed offset from UTC, e.g. of the business the schedules belong to
+    Fixed(FixedOffset),
+}
+
+impl TodayTimezone {
+    pub fn today(&self) -> NaiveDate {
+        match self {
+            TodayTimezone::Local => Local::now().naive_local().date(),
+            TodayTimezone::Utc => Utc::now().naive_utc().date(),
+            TodayTimezone::Fixed(offset) => Utc::now().with_timezone(offset).naive_local().date(),
+        }
+    }
+}
+
+/// `WeekdayLabelFormat` of the headers of the day grid.
+#[derive(Clone, Default)]
+pub enum WeekdayLabelFormat {
+    /// e.g. `Monday`
+    Long,
+    /// e.g. `Mon`
+    #[default]
+    Short,
+    /// e.g. `M`
+    Narrow,
+    /// label returned by the function, e.g. a translated one
+    Custom(Rc<dyn Fn(Weekday) -> String>),
+}
+
+impl WeekdayLabelFormat {
+    pub fn label(&self, weekday: Weekday) -> String {
+        match self {
+            WeekdayLabelFormat::Long => match weekday {
+                Weekday::Mon => "Monday",
+                Weekday::Tue => "Tuesday",
+                Weekday::Wed => "Wednesday",
+                Weekday::Thu => "Thursday",
+                Weekday::Fri => "Friday",
+                Weekday::Sat => "Saturday",
+                Weekday::Sun => "Sunday",
+            }
+            .to_string(),
+            WeekdayLabelFormat::Short => weekday.to_string(),
+            WeekdayLabelFormat::Narrow => weekday.to_string()[..1].to_string(),
+            WeekdayLabelFormat::Custom(label) => label(weekday),
+        }
+    }
+}
+
+impl fmt::Debug for WeekdayLabelFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WeekdayLabelFormat::Long => write!(f, "Long"),
+            WeekdayLabelFormat::Short => write!(f, "Short"),
+            WeekdayLabelFormat::Narrow => write!(f, "Narrow"),
+            WeekdayLabelFormat::Custom(_) => write!(f, "Custom"),
+        }
+    }
+}
+
+/// `DayLabelFormat` of the cells of the day grid.
+#[derive(Clone, Default)]
+pub enum DayLabelFormat {
+    /// e.g. `1`
+    #[default]
+    Numeric,
+    /// e.g. `01`
+    TwoDigit,
+    /// the short month name is added on the first day of each month, e.g. `1 Jan`, otherwise like `Numeric`
+    MonthOnFirstDay,
+    /// label returned by the function
+    Custom(Rc<dyn Fn(&NaiveDate) -> String>),
+}
+
+impl fmt::Debug for DayLabelFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DayLabelFormat::Numeric => write!(f, "Numeric"),
+            DayLabelFormat::TwoDigit => write!(f, "TwoDigit"),
+            DayLabelFormat::MonthOnFirstDay => write!(f, "MonthOnFirstDay"),
+            DayLabelFormat::Custom(_) => write!(f, "Custom"),
+        }
+    }
+}
+
+/// `MonthLabelFormat` of the cells of the months view.
+#[derive(Clone, Default)]
+pub enum MonthLabelFormat {
+    /// e.g. `3`
+    Numeric,
+    /// e.g. `Mar`
+    Short,
+    /// e.g. `March`
+    #[default]
+    Long,
+    /// label returned by the function
+    Custom(Rc<dyn Fn(&NaiveDate) -> String>),
+}
+
+impl fmt::Debug for MonthLabelFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MonthLabelFormat::Numeric => write!(f, "Numeric"),
+            MonthLabelFormat::Short => write!(f, "Short"),
+            MonthLabelFormat::Long => write!(f, "Long"),
+            MonthLabelFormat::Custom(_) => write!(f, "Custom"),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! Parsing of the dates typed or pasted into a text input, including a few natural language expressions
+//! like `today`, `next monday` or `+3d`, enabled by the `natural-language` feature.
+//!
+//! ```rust,ignore
+//! Msg::DateTyped(value, pasted) => {
+//!     if pasted {
+//!         if let Some(msg) = parse::selection_msg(&value, &model.date_picker) {
+//!             orders.send_msg(Msg::DatePicker(msg));
+//!         }
+//!     }
+//!     model.typed_date = value;
+//! }
+//! // in the view, the Enter or leaving the input selects the parsed date in the picker
+//! parse::view_input(&model.typed_date, &model.date_picker, Msg::DateTyped, Msg::DatePicker)
+//! ```
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use seed::{prelude::*, *};
+
+use crate::{
+    config::date_constraints::CheckDateConstraints, date::ViewedDateArithmetic,
+    options::TwoDigitYear, Model, Msg,
+};
+
+/// formats tried after the natural language expressions, the day always comes before the month,
+/// followed by the ISO 8601 week date and ordinal date
+const DATE_FORMATS: [&str; 6] = [
+    "%Y-%m-%d",
+    "%d.%m.%Y",
+    "%d/%m/%Y",
+    "%d %b %Y",
+    "%G-W%V-%u",
+    "%Y-%j",
+];
+
+/// Parses the `text` relative to `today`, case-insensitively:
+///
+/// - `today`, `tomorrow` and `yesterday`
+/// - a weekday, e.g. `friday` or `fri`, is its nearest occurrence from `today` on
+/// - `next friday` is the nearest one after `today`, `last friday` the nearest one before `today`
+/// - an offset in days, weeks, months or years, e.g. `+3d`, `-2w`, `+1m`, `+1y`,
+///   the day is clamped to the length of the target month
+/// - a date in one of the formats `2024-03-05`, `05.03.2024`, `05/03/2024` or `5 Mar 2024`
+/// - an ISO 8601 week date, e.g. `2024-W15-3` (Wednesday of the week 15), or an ordinal date, e.g. `2024-105`
+///
+/// The two-digit years, e.g. `05.03.24`, are read with the default `TwoDigitYear` policy.
+pub fn parse_date(text: &str, today: NaiveDate) -> Option<NaiveDate> {
+    parse_date_with(text, today, TwoDigitYear::default())
+}
+
+/// `parse_date` reading the day-first dates with a two-digit year, e.g. `05.03.24` or `5/3/24`,
+/// with the given `TwoDigitYear` policy
+pub fn parse_date_with(
+    text: &str,
+    today: NaiveDate,
+    two_digit_year: TwoDigitYear,
+) -> Option<NaiveDate> {
+    let trimmed = text.trim();
+    let text = trimmed.to_lowercase();
+    let mut words = text.split_whitespace();
+    match (words.next()?, words.next(), words.next()) {
+        ("today", None, _) => Some(today),
+        ("tomorrow", None, _) => today.succ_opt(),
+        ("yesterday", None, _) => today.pred_opt(),
+        ("next", Some(weekday), None) => {
+            nearest_weekday(today.succ_opt()?, weekday.parse().ok()?, 1)
+        }
+        ("last", Some(weekday), None) => {
+            nearest_weekday(today.pred_opt()?, weekday.parse().ok()?, -1)
+        }
+        (word, None, _) => word
+            .parse()
+            .ok()
+            .and_then(|weekday| nearest_weekday(today, weekday, 1))
+            .or_else(|| parse_offset(word, today))
+            .or_else(|| parse_formatted(trimmed, today, two_digit_year)),
+        // the formats are case-sensitive, e.g. the `W` of the week dates
+        _ => parse_formatted(trimmed, today, two_digit_year),
+    }
+}
+
+/// `Msg::DateSelected` of the date parsed from the `text` with the `two_digit_year` option if it can be selected in the `model`
+pub fn selection_msg<T: CheckDateConstraints + Default + Clone>(
+    text: &str,
+    model: &Model<T>,
+) -> Option<Msg> {
+    parse_date_with(
+        text,
+        model.options().today(),
+        *model.options().two_digit_year(),
+    )
+    .filter(|date| model.validate(*date).is_ok())
+    .map(Msg::DateSelected)
+}
+
+/// text input that sends the `selection_msg` of its `value` when the Enter is pressed or the input is left,
+/// `on_input` gets the typed value and whether it was pasted, so that the app can select a pasted date right away
+pub fn view_input<Ms: 'static, T: CheckDateConstraints + Default + Clone>(
+    value: &str,
+    model: &Model<T>,
+    on_input: impl FnOnce(String, bool) -> Ms + Clone + 'static,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+) -> Node<Ms> {
+    let entered_msg = selection_msg(value, model);
+    input![
+        attrs! {
+            At::Type => "text",
+            At::AutoComplete => "off",
+            At::Value => value,
+        },
+        ev(Ev::Input, move |event| {
+            let pasted = event
+                .dyn_ref::<web_sys::InputEvent>()
+                .is_some_and(|event| event.input_type() == "insertFromPaste");
+            let input = event
+                .target()?
+                .dyn_into::<web_sys::HtmlInputElement>()
+                .ok()?;
+            Some(on_input(input.value(), pasted))
+        }),
+        ev(Ev::Change, move |_| entered_msg.map(to_msg)),
+    ]
+}
+
+/// first `weekday` from the `start` on, going forward with the `step` 1 or backward with -1
+fn nearest_weekday(start: NaiveDate, weekday: Weekday, step: i64) -> Option<NaiveDate> {
+    (0..7)
+        .filter_map(|days| start.checked_add_signed(Duration::days(days * step)))
+        .find(|date| date.weekday() == weekday)
+}
+
+/// `today` moved by an offset like `+3d`, `-2w`, `+1m` or `+1y`
+fn parse_offset(word: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let sign = match word.chars().next()? {
+        '+' => 1,
+        '-' => -1,
+        _ => return None,
+    };
+    let unit = word.chars().last()?;
+    let amount: i32 = word.get(1..word.len() - unit.len_utf8())?.parse().ok()?;
+    let amount = sign * amount;
+    match unit {
+        'd' => today.checked_add_signed(Duration::days(amount.into())),
+        'w' => today.checked_add_signed(Duration::weeks(amount.into())),
+        'm' => Some(today.add_months(amount)),
+        'y' => Some(today.add_years(amount)),
+        _ => None,
+    }
+}
+
+fn parse_formatted(
+    text: &str,
+    today: NaiveDate,
+    two_digit_year: TwoDigitYear,
+) -> Option<NaiveDate> {
+    let text =
+        expand_two_digit_year(text, today, two_digit_year).unwrap_or_else(|| text.to_owned());
+    DATE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(&text, format).ok())
+}
+
+/// day-first `text` with its two-digit year replaced by the full one, `None` if it has no two-digit year
+fn expand_two_digit_year(
+    text: &str,
+    today: NaiveDate,
+    two_digit_year: TwoDigitYear,
+) -> Option<String> {
+    let is_separator = |character: char| matches!(character, '.' | '/' | '-');
+    let (day_and_month, year) = text.split_at(text.rfind(is_separator)? + 1);
+    let day = day_and_month.split(is_separator).next()?;
+    if year.len() != 2 || !year.chars().all(|digit| digit.is_ascii_digit()) || day.len() > 2 {
+        return None;
+    }
+    Some(format!(
+        "{}{}",
+        day_and_month,
+        two_digit_year.full_year(year.parse().ok()?, today)
+    ))
+}
@@ -0,0 +1,84 @@
+//! LocalStorage persistence of the last selected date, or of the last selected range
+//! in the `range_selection` mode, see the `persistence_key` option.
+use chrono::NaiveDate;
+use chrono_datepicker_core::config::{date_constraints::HasDateConstraints, PickerConfig};
+use seed::prelude::*;
+
+use crate::{config, range::DateRange};
+
+/// format of the stored dates
+const STORED_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// separator of the start and the end of a stored range
+const RANGE_SEPARATOR: &str = "..";
+
+/// `config` built with the `date_constraints` with the date stored under the `key` as its `initial_date`,
+/// the `config` is returned unchanged if nothing is stored or the stored date is forbidden
+pub(crate) fn restore<T: HasDateConstraints + Default + Clone>(
+    config: PickerConfig<T>,
+    date_constraints: &T,
+    key: &str,
+) -> PickerConfig<T> {
+    let stored_date = LocalStorage::get::<_, String>(key)
+        .ok()
+        .and_then(|value| parse_date(&value))
+        .filter(|date| !config.is_day_forbidden(date));
+    let stored_date = match stored_date {
+        Some(stored_date) => stored_date,
+        None => return config,
+    };
+    config::rebuild(&config)
+        .date_constraints(date_constraints.clone())
+        .initial_date(stored_date)
+        .build()
+        .unwrap_or(config)
+}
+
+/// range stored under the `key` written as `start..end`, `None` if nothing is stored
+/// or any of its ends is forbidden by the `config`
+pub(crate) fn restore_range<T: HasDateConstraints + Default + Clone>(
+    config: &PickerConfig<T>,
+    key: &str,
+) -> Option<DateRange> {
+    let value = LocalStorage::get::<_, String>(key).ok()?;
+    let mut dates = value.splitn(2, RANGE_SEPARATOR);
+    let range = DateRange::new(parse_date(dates.next()?)?, parse_date(dates.next()?)?);
+    if config.is_day_forbidden(&range.start()) || config.is_day_forbidden(&range.end()) {
+        return None;
+    }
+    Some(range)
+}
+
+/// stores the selected `date` under the `key`, the stored date is removed when the selection is cleared
+pub(crate) fn store(key: &str, date: Option<NaiveDate>) {
+    store_value(
+        key,
+        date.map(|date| date.format(STORED_DATE_FORMAT).to_string()),
+    );
+}
+
+/// stores the selected `range` under the `key`, the stored range is removed when the selection is cleared
+pub(crate) fn store_range(key: &str, range: Option<DateRange>) {
+    store_value(
+        key,
+        range.map(|range| {
+            format!(
+                "{}{}{}",
+                range.start().format(STORED_DATE_FORMAT),
+                RANGE_SEPARATOR,
+                range.end().format(STORED_DATE_FORMAT)
+            )
+        }),
+    );
+}
+
+fn store_value(key: &str, value: Option<String>) {
+    match value {
+        Some(value) => LocalStorage::insert(key, &value).ok(),
+        None => LocalStorage::remove(key).ok(),
+    };
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, STORED_DATE_FORMAT).ok()
+}
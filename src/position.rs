@@ -0,0 +1,67 @@
+//! Position of the dialog opened at an anchor element, e.g. the input displaying the selected date.
+use seed::{prelude::*, *};
+
+use crate::options::Placement;
+
+/// style placing the dialog at the `anchor` according to the `placement`, relative to the document,
+/// or relative to the viewport in the `fixed_position` mode, which isn't clipped by the `overflow` of the ancestors,
+/// `Placement::Auto` needs the already rendered `dialog` to measure it, without it the dialog is placed below the start
+pub(crate) fn anchored_style(
+    anchor: &web_sys::Element,
+    fixed_position: bool,
+    placement: Placement,
+    dialog: Option<&web_sys::Element>,
+) -> Style {
+    let rect = anchor.get_bounding_client_rect();
+    let (above, end) = match placement {
+        Placement::BelowStart => (false, false),
+        Placement::BelowEnd => (false, true),
+        Placement::Above => (true, false),
+        Placement::Auto => dialog.map_or((false, false), |dialog| {
+            let size = dialog.get_bounding_client_rect();
+            let (viewport_width, viewport_height) = viewport_size();
+            let space_below = viewport_height - rect.bottom();
+            let space_after = viewport_width - rect.left();
+            (
+                size.height() > space_below && rect.top() > space_below,
+                size.width() > space_after && rect.right() > space_after,
+            )
+        }),
+    };
+    let (scroll_x, scroll_y) = if fixed_position {
+        (0., 0.)
+    } else {
+        (
+            window().scroll_x().unwrap_or_default(),
+            window().scroll_y().unwrap_or_default(),
+        )
+    };
+    let left = if end { rect.right() } else { rect.left() };
+    let top = if above { rect.top() } else { rect.bottom() };
+    style! {
+        St::Position => if fixed_position { "fixed" } else { "absolute" },
+        St::Left => px(left + scroll_x),
+        St::Top => px(top + scroll_y),
+        St::Transform => format!(
+            "translate({}, {})",
+            if end { "-100%" } else { "0" },
+            if above { "-100%" } else { "0" }
+        ),
+    }
+}
+
+fn viewport_size() -> (f64, f64) {
+    let window = window();
+    (
+        window
+            .inner_width()
+            .ok()
+            .and_then(|width| width.as_f64())
+            .unwrap_or_default(),
+        window
+            .inner_height()
+            .ok()
+            .and_then(|height| height.as_f64())
+            .unwrap_or_default(),
+    )
+}
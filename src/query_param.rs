@@ -0,0 +1,111 @@
+//! Binding of the selection to a URL query parameter, so that the filters using the picker
+//! can be shared and bookmarked, e.g. `?date=2024-03-05` or `?stay=2024-03-05..2024-03-10` in the range mode:
+//!
+//! ```rust,ignore
+//! const QUERY_PARAM: QueryParam = QueryParam::new("date");
+//!
+//! fn init(url: Url, orders: &mut impl Orders<Msg>) -> Model {
+//!     let date_picker = seed_datepicker::init(url.clone(), orders, config, date_constraints, Msg::DatePicker);
+//!     for msg in QUERY_PARAM.selection_msgs(&url, &date_picker) {
+//!         orders.send_msg(Msg::DatePicker(msg));
+//!     }
+//!     Model { date_picker }
+//! }
+//! // in the update
+//! Msg::DateSelected(_) => QUERY_PARAM.write(&model.date_picker),
+//! ```
+use chrono::NaiveDate;
+use seed::prelude::*;
+
+use crate::{config::date_constraints::CheckDateConstraints, range::DateRange, Model, Msg};
+
+/// format of the dates in the parameter
+const PARAM_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// separator of the start and the end of a range in the parameter
+const RANGE_SEPARATOR: &str = "..";
+
+/// `QueryParam` is the name of the URL query parameter holding the selected date or range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryParam {
+    name: &'static str,
+}
+
+impl QueryParam {
+    pub const fn new(name: &'static str) -> Self {
+        Self { name }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// date of the parameter in the `url`, `None` if it is missing or isn't an ISO date
+    pub fn date(&self, url: &Url) -> Option<NaiveDate> {
+        parse_date(self.value(url)?)
+    }
+
+    /// range of the parameter in the `url` written as `start..end`, `None` if it is missing or malformed
+    pub fn range(&self, url: &Url) -> Option<DateRange> {
+        let mut dates = self.value(url)?.splitn(2, RANGE_SEPARATOR);
+        Some(DateRange::new(
+            parse_date(dates.next()?)?,
+            parse_date(dates.next()?)?,
+        ))
+    }
+
+    /// messages selecting the date or, with `range_selection`, both ends of the range of the `url`,
+    /// nothing if any of the dates can't be selected in the `model`
+    pub fn selection_msgs<T: CheckDateConstraints + Default + Clone>(
+        &self,
+        url: &Url,
+        model: &Model<T>,
+    ) -> Vec<Msg> {
+        let dates = if *model.options().range_selection() {
+            self.range(url)
+                .map_or_else(Vec::new, |range| vec![range.start(), range.end()])
+        } else {
+            self.date(url).into_iter().collect()
+        };
+        if dates.iter().any(|date| model.validate(*date).is_err()) {
+            return Vec::new();
+        }
+        dates.into_iter().map(Msg::DateSelected).collect()
+    }
+
+    /// writes the selection of the `model` into the current URL, replacing its history entry,
+    /// the parameter is removed when nothing is selected
+    pub fn write<T: CheckDateConstraints + Default + Clone>(&self, model: &Model<T>) {
+        let value = if *model.options().range_selection() {
+            model.selected_range().map(|range| {
+                format!(
+                    "{}{}{}",
+                    range.start().format(PARAM_DATE_FORMAT),
+                    RANGE_SEPARATOR,
+                    range.end().format(PARAM_DATE_FORMAT)
+                )
+            })
+        } else {
+            model
+                .selected_date()
+                .map(|date| date.format(PARAM_DATE_FORMAT).to_string())
+        };
+        let mut url = Url::current();
+        match value {
+            Some(value) => url.search_mut().insert(self.name.to_owned(), vec![value]),
+            None => url.search_mut().remove(self.name),
+        };
+        url.go_and_replace();
+    }
+
+    fn value<'a>(&self, url: &'a Url) -> Option<&'a str> {
+        url.search()
+            .get(self.name)
+            .and_then(|values| values.first())
+            .map(String::as_str)
+    }
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, PARAM_DATE_FORMAT).ok()
+}
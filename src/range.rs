@@ -0,0 +1,76 @@
+//! Range of dates selected in the `range_selection` mode, with the helpers for computing
+//! the prices or the durations of the stays, e.g. `len_nights` or `iter_days`.
+use chrono::NaiveDate;
+
+/// `DateRange` is a range of dates including both its `start` and its `end`,
+/// the selection of the `range_selection` mode, the `start` is never after the `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateRange {
+    start: NaiveDate,
+    end: NaiveDate,
+}
+
+impl DateRange {
+    /// range between the two dates in any order
+    pub fn new(a: NaiveDate, b: NaiveDate) -> Self {
+        if a <= b {
+            Self { start: a, end: b }
+        } else {
+            Self { start: b, end: a }
+        }
+    }
+
+    /// first day of the range
+    pub fn start(&self) -> NaiveDate {
+        self.start
+    }
+
+    /// last day of the range
+    pub fn end(&self) -> NaiveDate {
+        self.end
+    }
+
+    /// whether the `date` is between the `start` and the `end`, both included
+    pub fn contains(&self, date: &NaiveDate) -> bool {
+        self.start <= *date && *date <= self.end
+    }
+
+    /// number of days in the range, 1 if the `start` is the `end`
+    pub fn len_days(&self) -> i64 {
+        (self.end - self.start).num_days() + 1
+    }
+
+    /// number of nights in the range, i.e. days without the last one
+    pub fn len_nights(&self) -> i64 {
+        (self.end - self.start).num_days()
+    }
+
+    /// all the days of the range from the `start` to the `end`
+    pub fn iter_days(&self) -> impl Iterator<Item = NaiveDate> {
+        let end = self.end;
+        self.start.iter_days().take_while(move |day| *day <= end)
+    }
+
+    /// whether the ranges share at least one day
+    pub fn intersects(&self, other: &DateRange) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// days shared by both ranges, `None` if there are none
+    pub fn intersection(&self, other: &DateRange) -> Option<DateRange> {
+        if self.intersects(other) {
+            Some(DateRange {
+                start: self.start.max(other.start),
+                end: self.end.min(other.end),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl From<(NaiveDate, NaiveDate)> for DateRange {
+    fn from((a, b): (NaiveDate, NaiveDate)) -> Self {
+        Self::new(a, b)
+    }
+}
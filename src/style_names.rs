@@ -0,0 +1,97 @@
+pub use chrono_datepicker_core::style_names::*;
+
+/// added to the datepicker root when the dark color scheme is used
+pub const DARK: &str = "dark";
+
+/// footer of the dialog with the confirmation buttons
+pub const FOOTER: &str = "footer";
+
+/// button confirming the staged date
+pub const CONFIRM: &str = "confirm";
+
+/// button discarding the staged date
+pub const CANCEL: &str = "cancel";
+
+/// button restoring the previous selection
+pub const UNDO: &str = "undo";
+
+/// button restoring the undone selection
+pub const REDO: &str = "redo";
+
+/// first day of a selected range
+pub const RANGE_START: &str = "range-start";
+
+/// last day of a selected range
+pub const RANGE_END: &str = "range-end";
+
+/// days between the start and the end of a selected range, or of the previewed one
+pub const IN_RANGE: &str = "in-range";
+
+/// cell of the current day, month or year
+pub const TODAY: &str = "today";
+
+/// semi-transparent backdrop behind the dialog in the `modal` mode
+pub const BACKDROP: &str = "datepicker-backdrop";
+
+/// visually hidden text announcing the displayed period to the screen readers
+pub const LIVE_REGION: &str = "live-region";
+
+/// legend of the styles of the unavailable days, see the `legend` option
+pub const LEGEND: &str = "legend";
+
+/// sample of the style of a rule in the legend
+pub const SWATCH: &str = "swatch";
+
+/// day before the `min_date` or after the `max_date`
+pub const OUT_OF_RANGE: &str = "out-of-range";
+
+/// day of a disabled weekday
+pub const DISABLED_WEEKDAY: &str = "disabled-weekday";
+
+/// day of a disabled month
+pub const DISABLED_MONTH: &str = "disabled-month";
+
+/// day of a disabled year
+pub const DISABLED_YEAR: &str = "disabled-year";
+
+/// day of month disabled in all months
+pub const DISABLED_MONTHLY_DATE: &str = "disabled-monthly-date";
+
+/// day disabled in every year
+pub const DISABLED_YEARLY_DATE: &str = "disabled-yearly-date";
+
+/// particular disabled date
+pub const DISABLED_UNIQUE_DATE: &str = "disabled-unique-date";
+
+/// overlay covering the body of the dialog while it is loading, see `Msg::SetLoading`
+pub const LOADING_OVERLAY: &str = "loading-overlay";
+
+/// spinner in the middle of the loading overlay
+pub const SPINNER: &str = "spinner";
+
+/// cell of a day of another month, when the `show_other_month_days` option is off
+pub const EMPTY_CELL: &str = "empty-cell";
+
+/// body of the days view with the `DaysLayout::WeekStrip`
+pub const WEEK_STRIP: &str = "week-strip";
+
+/// body of the days view with the `DaysLayout::Agenda`
+pub const AGENDA: &str = "agenda";
+
+/// row of a day in the agenda, with its weekday and its cell
+pub const AGENDA_DAY: &str = "agenda-day";
+
+/// message of the agenda without any selectable day
+pub const AGENDA_EMPTY: &str = "agenda-empty";
+
+/// added to the datepicker root while it plays the closing animation, see the `closing_duration` option
+pub const CLOSING: &str = "closing";
+
+/// added to the datepicker root positioned by the CSS anchor positioning, see the `anchor_name` option
+pub const ANCHORED: &str = "anchored";
+
+/// node appended to `document.body` that the dialog is rendered into with the `portal` option
+pub const PORTAL: &str = "datepicker-portal";
+
+/// element wrapping the dialog with the `dom_events` option, it stays rendered while the dialog is closed
+pub const EVENT_TARGET: &str = "datepicker-events";
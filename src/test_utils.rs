@@ -0,0 +1,158 @@
+use std::collections::HashSet;
+
+use chrono::prelude::*;
+use chrono_datepicker_core::{
+    config::{date_constraints::HasDateConstraints, PickerConfig},
+    dialog_view_type::DialogViewType,
+    viewed_date::YearNumber,
+};
+use seed::prelude::*;
+
+use crate::{
+    config::date_constraints::CheckDateConstraints,
+    headless::{SelectionState, ViewState},
+    navigation::{initial_viewed_date, year_group_range},
+    options::PickerOptions,
+    Model,
+};
+
+/// `ModelBuilder` constructs a `Model` with an arbitrary state without going through `init`,
+/// so that the `update` flows can be tested without a `Url` and `Orders`.
+///
+/// Everything that is not set explicitly is initialized the same way as in `init`.
+pub struct ModelBuilder<T>
+where
+    T: HasDateConstraints + Default + Clone,
+{
+    config: PickerConfig<T>,
+    date_constraints: T,
+    options: PickerOptions,
+    selected_date: Option<NaiveDate>,
+    viewed_date: Option<NaiveDate>,
+    dialog_view_type: Option<DialogViewType>,
+    dialog_opened: Option<bool>,
+}
+
+impl<T: HasDateConstraints + Default + Clone> ModelBuilder<T> {
+    /// builder of a `Model` with the `config` built with the `date_constraints`
+    pub fn new(config: PickerConfig<T>, date_constraints: T) -> Self {
+        Self {
+            selected_date: *config.initial_date(),
+            config,
+            date_constraints,
+            options: PickerOptions::default(),
+            viewed_date: None,
+            dialog_view_type: None,
+            dialog_opened: None,
+        }
+    }
+
+    pub fn options(mut self, options: PickerOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn selected_date(mut self, selected_date: Option<NaiveDate>) -> Self {
+        self.selected_date = selected_date;
+        self
+    }
+
+    pub fn viewed_date(mut self, viewed_date: NaiveDate) -> Self {
+        self.viewed_date = Some(viewed_date);
+        self
+    }
+
+    pub fn dialog_view_type(mut self, dialog_view_type: DialogViewType) -> Self {
+        self.dialog_view_type = Some(dialog_view_type);
+        self
+    }
+
+    pub fn dialog_opened(mut self, dialog_opened: bool) -> Self {
+        self.dialog_opened = Some(dialog_opened);
+        self
+    }
+
+    pub fn build(self) -> Model<T> {
+        Model {
+            selection: SelectionState::new(self.selected_date),
+            hovered_date: None,
+            dialog_opened: self
+                .dialog_opened
+                .unwrap_or(*self.config.initially_opened()),
+            loading: false,
+            view: ViewState {
+                viewed_date: self
+                    .viewed_date
+                    .unwrap_or_else(|| initial_viewed_date(&self.config, self.options.today())),
+                dialog_view_type: self
+                    .dialog_view_type
+                    .unwrap_or(*self.config.initial_view_type()),
+            },
+            dialog_position_style: None,
+            anchor: None,
+            anchor_streams: Vec::new(),
+            config: self.config,
+            date_constraints: self.date_constraints,
+            options: self.options,
+            focus_ref: ElRef::default(),
+            root_ref: ElRef::default(),
+            events_ref: ElRef::default(),
+            portal_ref: ElRef::default(),
+            typed_day: String::new(),
+            typed_day_timeout: None,
+            closing_timeout: None,
+            auto_close_timeout: None,
+            debounced_change: None,
+            hold_delay: None,
+            hold_repeat: None,
+            navigation_held: false,
+            month_hold_delay: None,
+            month_held: false,
+            color_scheme_listener: None,
+            prefers_dark: false,
+            open_registration: None,
+        }
+    }
+}
+
+/// `StubConstraints` is a test double of the date constraints that forbids exactly the given days,
+/// the months, years and year groups are forbidden when all of their days are.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StubConstraints {
+    pub forbidden: HashSet<NaiveDate>,
+}
+
+impl StubConstraints {
+    pub fn new(forbidden: impl IntoIterator<Item = NaiveDate>) -> Self {
+        Self {
+            forbidden: forbidden.into_iter().collect(),
+        }
+    }
+}
+
+impl HasDateConstraints for StubConstraints {
+    fn is_day_forbidden(&self, date: &NaiveDate) -> bool {
+        self.forbidden.contains(date)
+    }
+
+    fn is_month_forbidden(&self, year_month_info: &NaiveDate) -> bool {
+        let first_day = year_month_info.with_day(1).unwrap_or(*year_month_info);
+        first_day
+            .iter_days()
+            .take_while(|date| date.month() == first_day.month())
+            .all(|date| self.is_day_forbidden(&date))
+    }
+
+    fn is_year_forbidden(&self, year: YearNumber) -> bool {
+        (1..=12).all(|month| {
+            NaiveDate::from_ymd_opt(year, month, 1)
+                .is_none_or(|first_day| self.is_month_forbidden(&first_day))
+        })
+    }
+
+    fn is_year_group_forbidden(&self, year: YearNumber) -> bool {
+        year_group_range(year).all(|year| self.is_year_forbidden(year))
+    }
+}
+
+impl CheckDateConstraints for StubConstraints {}
@@ -0,0 +1,154 @@
+//! `<seed-datepicker>` custom element for the pages without Seed or Rust, enabled by the `web-component` feature.
+//!
+//! Once `defineCustomElement()` of the generated JS module is called, the element can be used in plain HTML,
+//! its `min` and `max` attributes are ISO dates limiting the selectable days and `format` is the chrono format
+//! of the date displayed in its input, e.g. `%d.%m.%Y`, an invalid one falls back to the ISO dates:
+//!
+//! ```html
+//! <seed-datepicker min="2024-01-01" max="2024-12-31" format="%d.%m.%Y"></seed-datepicker>
+//! <script type="module">
+//!     import init, { defineCustomElement } from "./seed_datepicker.js";
+//!     await init();
+//!     defineCustomElement();
+//!     document.querySelector("seed-datepicker")
+//!         .addEventListener("datepicker-change", (event) => console.log(event.detail));
+//! </script>
+//! ```
+//!
+//! Every selection dispatches the `datepicker-change` `CustomEvent` with the ISO date, or `null`, in its `detail`.
+use chrono::NaiveDate;
+use seed::{prelude::*, *};
+
+use crate::{
+    config::{
+        date_constraints::{DateConstraints, DateConstraintsBuilder},
+        PickerConfigBuilder,
+    },
+    dom_events,
+    format::ValueFormat,
+    options::PickerOptionsBuilder,
+    Model, Msg,
+};
+
+/// tag name of the custom element
+pub const ELEMENT_NAME: &str = "seed-datepicker";
+
+/// format of the dates in the attributes
+const ISO_DATE_FORMAT: &str = "%Y-%m-%d";
+
+#[wasm_bindgen(inline_js = r#"
+export function define_element(name, connected) {
+    if (customElements.get(name)) {
+        return;
+    }
+    customElements.define(name, class extends HTMLElement {
+        connectedCallback() {
+            connected(this);
+        }
+    });
+}
+"#)]
+extern "C" {
+    fn define_element(name: &str, connected: &Closure<dyn FnMut(web_sys::HtmlElement)>);
+}
+
+/// registers the `<seed-datepicker>` custom element, each connected element starts its own Seed app,
+/// calling it again does nothing
+#[wasm_bindgen(js_name = defineCustomElement)]
+pub fn define_custom_element() {
+    let connected = Closure::wrap(Box::new(|element: web_sys::HtmlElement| {
+        App::start(
+            element.clone(),
+            move |url, orders| init(url, orders, element),
+            update,
+            view,
+        );
+    }) as Box<dyn FnMut(web_sys::HtmlElement)>);
+    define_element(ELEMENT_NAME, &connected);
+    // the element can be connected any time later
+    connected.forget();
+}
+
+struct ElementModel {
+    element: web_sys::HtmlElement,
+    date_picker: Model<DateConstraints>,
+}
+
+enum ElementMsg {
+    DatePicker(Msg),
+    DateSelected(Option<NaiveDate>),
+}
+
+fn init(
+    url: Url,
+    orders: &mut impl Orders<ElementMsg>,
+    element: web_sys::HtmlElement,
+) -> ElementModel {
+    let date_attribute = |name| {
+        element
+            .get_attribute(name)
+            .and_then(|value| NaiveDate::parse_from_str(&value, ISO_DATE_FORMAT).ok())
+    };
+    let mut date_constraints = DateConstraintsBuilder::default();
+    if let Some(min_date) = date_attribute("min") {
+        date_constraints.min_date(min_date);
+    }
+    if let Some(max_date) = date_attribute("max") {
+        date_constraints.max_date(max_date);
+    }
+    // the `min` later than the `max` is ignored
+    let date_constraints = date_constraints.build().unwrap_or_default();
+    let config = PickerConfigBuilder::default()
+        .date_constraints(date_constraints.clone())
+        .build()
+        .expect("config without an initial date is valid");
+    let mut options = PickerOptionsBuilder::default();
+    if let Some(value_format) = element
+        .get_attribute("format")
+        .map(|format| ValueFormat::from(format.as_str()))
+        .filter(ValueFormat::is_valid)
+    {
+        options.value_format(value_format);
+    }
+    let options = options.build().expect("options with defaults are valid");
+    ElementModel {
+        date_picker: crate::init_with_options(
+            url,
+            orders,
+            config,
+            date_constraints,
+            options,
+            ElementMsg::DatePicker,
+        ),
+        element,
+    }
+}
+
+fn update(msg: ElementMsg, model: &mut ElementModel, orders: &mut impl Orders<ElementMsg>) {
+    match msg {
+        ElementMsg::DatePicker(msg) => crate::update(
+            msg,
+            &mut model.date_picker,
+            orders,
+            ElementMsg::DateSelected,
+            ElementMsg::DatePicker,
+        ),
+        ElementMsg::DateSelected(date) => {
+            dom_events::dispatch(&model.element, dom_events::CHANGE_EVENT, date)
+        }
+    }
+}
+
+fn view(model: &ElementModel) -> Node<ElementMsg> {
+    div![
+        input![
+            attrs! {
+                At::Type => "text",
+                At::ReadOnly => "",
+                At::Value => model.date_picker.formatted_value().unwrap_or_default(),
+            },
+            ev(Ev::Click, |_| ElementMsg::DatePicker(Msg::OpenDialog(None))),
+        ],
+        crate::view(&model.date_picker, ElementMsg::DatePicker),
+    ]
+}
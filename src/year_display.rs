@@ -0,0 +1,72 @@
+//! Display of the year numbers in the years view and in the title,
+//! the `NaiveDate`s of the picker always stay Gregorian.
+use chrono_datepicker_core::viewed_date::YearNumber;
+
+/// `YearDisplay` turns a Gregorian year number into the text displayed by the dialog.
+pub trait YearDisplay {
+    fn display_year(&self, year: YearNumber) -> String;
+}
+
+/// plain Gregorian year numbers, e.g. `2024`
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GregorianYears;
+
+impl YearDisplay for GregorianYears {
+    fn display_year(&self, year: YearNumber) -> String {
+        year.to_string()
+    }
+}
+
+/// years of the Common Era, the astronomical year 0 and the negative years are displayed as BCE,
+/// e.g. `0` is `1 BCE` and `-43` is `44 BCE`
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CommonEra;
+
+impl YearDisplay for CommonEra {
+    fn display_year(&self, year: YearNumber) -> String {
+        if year > 0 {
+            year.to_string()
+        } else {
+            format!("{} BCE", 1 - year)
+        }
+    }
+}
+
+/// years of the Buddhist Era, e.g. `BE 2567`
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BuddhistEra;
+
+/// difference between the Buddhist Era and the Gregorian year numbers
+const BUDDHIST_ERA_OFFSET: YearNumber = 543;
+
+impl YearDisplay for BuddhistEra {
+    fn display_year(&self, year: YearNumber) -> String {
+        format!("BE {}", year + BUDDHIST_ERA_OFFSET)
+    }
+}
+
+/// Japanese era names, e.g. `Reiwa 6`, a year in which the era changed belongs to the new era,
+/// the years before the Meiji era are displayed as Gregorian
+#[derive(Debug, Default, Clone, Copy)]
+pub struct JapaneseEra;
+
+/// names of the Japanese eras and the Gregorian years they started in, from the latest one
+const JAPANESE_ERAS: [(&str, YearNumber); 5] = [
+    ("Reiwa", 2019),
+    ("Heisei", 1989),
+    ("Showa", 1926),
+    ("Taisho", 1912),
+    ("Meiji", 1868),
+];
+
+impl YearDisplay for JapaneseEra {
+    fn display_year(&self, year: YearNumber) -> String {
+        JAPANESE_ERAS
+            .iter()
+            .find(|(_, first_year)| year >= *first_year)
+            .map_or_else(
+                || year.to_string(),
+                |(name, first_year)| format!("{} {}", name, year - first_year + 1),
+            )
+    }
+}
@@ -0,0 +1,177 @@
+//! `DatePicker` component for the Yew apps, enabled by the `yew` feature.
+//!
+//! It shares the `PickerConfig`, the `PickerOptions` and the date constraints with the Seed pickers
+//! and is driven by the renderer-agnostic `headless::SelectionState` and `headless::ViewState`,
+//! so it navigates and zooms out to the months and the years like the Seed view.
+//! The markup uses the same class names, so the `seed-datepicker.scss` styles apply:
+//!
+//! ```rust,ignore
+//! html! {
+//!     if *opened {
+//!         <DatePicker<DateConstraints>
+//!             config={config.clone()}
+//!             options={options.clone()}
+//!             on_change={Callback::from(|date| log::info!("New date was selected: {:?}", date))}
+//!             on_close={Callback::from(move |_| opened.set(false))}
+//!         />
+//!     }
+//! }
+//! ```
+use std::rc::Rc;
+
+use chrono::NaiveDate;
+use chrono_datepicker_core::{
+    config::{date_constraints::HasDateConstraints, PickerConfig},
+    dialog_view_type::DialogViewType,
+};
+use yew::prelude::*;
+
+use crate::{
+    headless::{weekday_labels, SelectionState, ViewChange, ViewMsg, ViewState},
+    options::PickerOptions,
+    style_names::*,
+};
+
+/// `DatePickerProps` describe the `DatePicker`, the `config` and the `options` are compared by the pointer,
+/// so a new `Rc` has to be passed to change them.
+#[derive(Properties)]
+pub struct DatePickerProps<T: HasDateConstraints + Default + Clone + 'static> {
+    /// the same config as passed to the Seed `init`
+    pub config: Rc<PickerConfig<T>>,
+
+    /// the same options as passed to the Seed `init_with_options`
+    #[prop_or_default]
+    pub options: Rc<PickerOptions>,
+
+    /// receives the newly selected date
+    #[prop_or_default]
+    pub on_change: Callback<Option<NaiveDate>>,
+
+    /// emitted when the close button is clicked or a date gets selected
+    #[prop_or_default]
+    pub on_close: Callback<()>,
+}
+
+impl<T: HasDateConstraints + Default + Clone + 'static> PartialEq for DatePickerProps<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.config, &other.config)
+            && Rc::ptr_eq(&self.options, &other.options)
+            && self.on_change == other.on_change
+            && self.on_close == other.on_close
+    }
+}
+
+/// Yew counterpart of the Seed `view`
+#[function_component(DatePicker)]
+pub fn date_picker<T: HasDateConstraints + Default + Clone + 'static>(
+    props: &DatePickerProps<T>,
+) -> Html {
+    let config = props.config.clone();
+    let options = props.options.clone();
+    let selection = use_state(|| SelectionState::new(*config.initial_date()));
+    let view = use_state(|| ViewState::new(&*config, &options));
+
+    let select = {
+        let selection = selection.clone();
+        let on_change = props.on_change.clone();
+        let on_close = props.on_close.clone();
+        Callback::from(move |date: NaiveDate| {
+            let mut new_selection = (*selection).clone();
+            new_selection.select(date);
+            selection.set(new_selection);
+            on_change.emit(Some(date));
+            on_close.emit(());
+        })
+    };
+    // a month or a year of the `selection_type` clicked in the months or the years view gets selected
+    let update_view = {
+        let (config, options) = (config.clone(), options.clone());
+        let view = view.clone();
+        let select = select.clone();
+        Callback::from(move |msg: ViewMsg| {
+            let mut new_view = *view;
+            let change = new_view.update(msg, &*config, &options);
+            view.set(new_view);
+            if let ViewChange::Selected(date) = change {
+                select.emit(date);
+            }
+        })
+    };
+
+    let navigation_button = |class_name: &'static str, label: &'static str, msg: ViewMsg| {
+        let available = if msg == ViewMsg::Next {
+            view.can_navigate_next(&*config, &options)
+        } else {
+            view.can_navigate_previous(&*config, &options)
+        };
+        let onclick = update_view.reform(move |_: MouseEvent| msg);
+        html! {
+            <button class={classes!(BUTTON, class_name)} disabled={!available} {onclick}>{ label }</button>
+        }
+    };
+
+    let cells = view
+        .cells(&*config, &options, &selection)
+        .into_iter()
+        .map(|cell| {
+            if cell.is_empty {
+                return html! { <span class={EMPTY_CELL} role="gridcell"></span> };
+            }
+            let onclick = (!cell.is_forbidden).then(|| {
+                let (update_view, select) = (update_view.clone(), select.clone());
+                let (date, view_msg) = (cell.date, cell.view_msg);
+                Callback::from(move |_: MouseEvent| match view_msg {
+                    Some(msg) => update_view.emit(msg),
+                    None => {
+                        update_view.emit(ViewMsg::Navigate(date));
+                        select.emit(date);
+                    }
+                })
+            });
+            html! {
+                <span
+                    class={classes!(
+                        if cell.is_forbidden { UNAVAILABLE } else { SELECTABLE },
+                        cell.is_other_month.then_some(OTHER_MONTH),
+                        cell.is_selected.then_some(SELECTED),
+                        cell.is_today.then_some(TODAY),
+                    )}
+                    role="gridcell"
+                    aria-selected={cell.is_selected.to_string()}
+                    data-date={cell.date.format("%Y-%m-%d").to_string()}
+                    {onclick}
+                >
+                    { cell.label }
+                </span>
+            }
+        });
+    let weekday_names = if view.dialog_view_type() == &DialogViewType::Days {
+        weekday_labels(&options)
+    } else {
+        Vec::new()
+    }
+    .into_iter()
+    .map(|label| html! { <span class={GRID_HEADER} role="columnheader">{ label }</span> });
+    let zoom_out = update_view.reform(|_: MouseEvent| ViewMsg::ZoomOut);
+    let on_close = props.on_close.reform(|_: MouseEvent| ());
+
+    html! {
+        <div class={DATEPICKER_ROOT}>
+            <div class={HEADER}>
+                { navigation_button(PREVIOUS, "«", ViewMsg::Previous) }
+                <span class={TITLE} role="heading" onclick={zoom_out}>
+                    { view.title(&*config, &options) }
+                </span>
+                { navigation_button(NEXT, "»", ViewMsg::Next) }
+                <button class={classes!(BUTTON, CLOSE)} onclick={on_close}>{ "x" }</button>
+            </div>
+            <div
+                class={BODY}
+                style={format!("grid-template-columns: {}", "1fr ".repeat(view.grid_columns(&options)))}
+            >
+                { for weekday_names }
+                { for cells }
+            </div>
+        </div>
+    }
+}
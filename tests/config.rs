@@ -0,0 +1,176 @@
+//! Tests of the validation of the config, they run natively without wasm.
+use chrono::{Datelike, NaiveDate, Weekday};
+use seed_datepicker::{
+    config::{
+        date_constraints::{
+            ConstraintRule, DateConstraints, DateConstraintsBuilder, DateConstraintsError,
+            HasDateConstraints,
+        },
+        validate_on, ConfigError, PickerConfigBuilder, ValidatedBuild,
+    },
+    dialog_view_type::DialogViewType,
+    options::PickerOptionsBuilder,
+};
+
+/// current date of the validation, so that the tests don't depend on the clock
+fn today() -> NaiveDate {
+    ymd(2024, 3, 14)
+}
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("Invalid date")
+}
+
+#[test]
+fn constraints_error_keeps_its_variant() {
+    let error: ConfigError = DateConstraintsBuilder::default()
+        .min_date(ymd(2024, 3, 5))
+        .max_date(ymd(2024, 3, 1))
+        .build()
+        .unwrap_err()
+        .into();
+    assert_eq!(
+        error,
+        ConfigError::Constraints(DateConstraintsError::MinAfterMax {
+            min_date: ymd(2024, 3, 5),
+            max_date: ymd(2024, 3, 1),
+        })
+    );
+}
+
+#[test]
+fn builder_error_keeps_its_message() {
+    let result = PickerConfigBuilder::default()
+        .date_constraints(
+            DateConstraintsBuilder::default()
+                .min_date(ymd(2024, 3, 5))
+                .build()
+                .unwrap(),
+        )
+        .initial_date(ymd(2024, 3, 1))
+        .build_validated_on(today());
+    assert!(matches!(result, Err(ConfigError::Builder(_))));
+}
+
+#[test]
+fn initial_view_can_not_display_smaller_periods_than_are_selected() {
+    // the PickerConfigBuilder of chrono-datepicker-core already rejects it
+    let result = PickerConfigBuilder::<DateConstraints>::default()
+        .initial_view_type(DialogViewType::Days)
+        .selection_type(DialogViewType::Months)
+        .build_validated_on(today());
+    assert!(matches!(result, Err(ConfigError::Builder(_))));
+}
+
+/// constraints forbidding the month of March 2024 while its days stay allowed
+#[derive(Debug, Default, Clone)]
+struct ForbiddenMarch;
+
+impl HasDateConstraints for ForbiddenMarch {
+    fn is_day_forbidden(&self, _: &NaiveDate) -> bool {
+        false
+    }
+
+    fn is_month_forbidden(&self, year_month_info: &NaiveDate) -> bool {
+        year_month_info.year() == 2024 && year_month_info.month() == 3
+    }
+
+    fn is_year_forbidden(&self, _: i32) -> bool {
+        false
+    }
+
+    fn is_year_group_forbidden(&self, _: i32) -> bool {
+        false
+    }
+}
+
+#[test]
+fn initial_date_in_a_forbidden_month_is_rejected() {
+    let result = PickerConfigBuilder::default()
+        .date_constraints(ForbiddenMarch)
+        .selection_type(DialogViewType::Months)
+        .initial_view_type(DialogViewType::Months)
+        .initial_date(ymd(2024, 3, 5))
+        .build_validated_on(today());
+    assert_eq!(
+        result.unwrap_err(),
+        ConfigError::InitialDateForbidden(ymd(2024, 3, 5))
+    );
+}
+
+#[test]
+fn constraints_without_any_selectable_date_are_rejected() {
+    let constraints = DateConstraintsBuilder::default()
+        .disabled_weekdays(
+            [
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+                Weekday::Sat,
+                Weekday::Sun,
+            ]
+            .iter()
+            .copied()
+            .collect(),
+        )
+        .build()
+        .unwrap();
+    let result = PickerConfigBuilder::default()
+        .date_constraints(constraints)
+        .build_validated_on(today());
+    assert_eq!(result.unwrap_err(), ConfigError::NothingSelectable);
+}
+
+#[test]
+fn distant_min_date_is_selectable() {
+    let constraints = DateConstraintsBuilder::default()
+        .min_date(ymd(2231, 7, 20))
+        .build()
+        .unwrap();
+    assert!(PickerConfigBuilder::default()
+        .date_constraints(constraints.clone())
+        .build_validated_on(today())
+        .is_ok());
+    let config = PickerConfigBuilder::default()
+        .date_constraints(constraints)
+        .build()
+        .unwrap();
+    assert_eq!(validate_on(&config, today()), Ok(()));
+}
+
+#[test]
+fn invalid_js_error_is_displayed_with_its_message() {
+    assert_eq!(
+        ConfigError::InvalidJs("disabled must be an array".to_owned()).to_string(),
+        "invalid JS config: disabled must be an array"
+    );
+}
+
+#[test]
+fn legend_and_cell_rules_come_from_the_same_constraints() {
+    let constraints = DateConstraintsBuilder::default()
+        .min_date(ymd(2024, 1, 1))
+        .disabled_weekdays([Weekday::Sat, Weekday::Sun].iter().cloned().collect())
+        .build()
+        .unwrap();
+    let options = PickerOptionsBuilder::default()
+        .constraint_rules(constraints)
+        .build()
+        .unwrap();
+    assert_eq!(
+        options.legend(),
+        &vec![ConstraintRule::OutOfRange, ConstraintRule::DisabledWeekdays]
+    );
+    let forbidden_rule = options.forbidden_rule().as_ref().unwrap();
+    assert_eq!(
+        forbidden_rule(&ymd(2024, 3, 16)),
+        Some(ConstraintRule::DisabledWeekdays)
+    );
+    assert_eq!(
+        forbidden_rule(&ymd(2023, 12, 29)),
+        Some(ConstraintRule::OutOfRange)
+    );
+    assert_eq!(forbidden_rule(&ymd(2024, 3, 15)), None);
+}
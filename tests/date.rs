@@ -0,0 +1,53 @@
+//! Tests of the date arithmetic at the ends of the range of `NaiveDate`, they run natively without wasm.
+use chrono::{Datelike, NaiveDate};
+use seed_datepicker::{
+    calendar::{CalendarSystem, GregorianCalendar},
+    date::ViewedDateArithmetic,
+};
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("Invalid date")
+}
+
+#[test]
+fn months_are_clamped_to_the_length_of_the_month() {
+    assert_eq!(ymd(2024, 1, 31).add_months(1), ymd(2024, 2, 29));
+    assert_eq!(ymd(2024, 2, 29).add_years(1), ymd(2025, 2, 28));
+    assert_eq!(ymd(2024, 3, 5).add_weeks(-1), ymd(2024, 2, 27));
+}
+
+#[test]
+fn jumps_saturate_at_the_max_date() {
+    let last_month = NaiveDate::MAX.with_day(1).unwrap();
+    assert_eq!(NaiveDate::MAX.add_weeks(1), NaiveDate::MAX);
+    assert_eq!(last_month.add_months(1), last_month);
+    assert_eq!(NaiveDate::MAX.add_months(i32::MAX), NaiveDate::MAX);
+    assert_eq!(NaiveDate::MAX.add_years(i32::MAX), NaiveDate::MAX);
+    assert_eq!(NaiveDate::MAX.add_year_groups(1), NaiveDate::MAX);
+}
+
+#[test]
+fn jumps_saturate_at_the_min_date() {
+    assert_eq!(NaiveDate::MIN.add_weeks(-1), NaiveDate::MIN);
+    assert_eq!(NaiveDate::MIN.add_months(-1), NaiveDate::MIN);
+    assert_eq!(NaiveDate::MIN.add_months(i32::MIN), NaiveDate::MIN);
+    assert_eq!(NaiveDate::MIN.add_years(i32::MIN), NaiveDate::MIN);
+    assert_eq!(NaiveDate::MIN.add_year_groups(-1), NaiveDate::MIN);
+}
+
+#[test]
+fn calendar_offsets_saturate_at_both_ends() {
+    let calendar = GregorianCalendar;
+    assert_eq!(calendar.month_offset(&NaiveDate::MAX, 1), NaiveDate::MAX);
+    assert_eq!(calendar.year_offset(&NaiveDate::MAX, 1), NaiveDate::MAX);
+    assert_eq!(calendar.month_offset(&NaiveDate::MIN, -1), NaiveDate::MIN);
+    assert_eq!(calendar.year_offset(&NaiveDate::MIN, -1), NaiveDate::MIN);
+    assert_eq!(
+        calendar.first_day_of_month(&NaiveDate::MAX),
+        NaiveDate::MAX.with_day(1).unwrap()
+    );
+    assert_eq!(
+        calendar.with_day_clamped(&ymd(2023, 2, 1), 31),
+        ymd(2023, 2, 28)
+    );
+}
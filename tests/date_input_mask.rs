@@ -0,0 +1,63 @@
+//! Tests of the masked input of a typed date, they run natively without wasm.
+use chrono::NaiveDate;
+use seed_datepicker::date_input_mask::DateInputMask;
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("Invalid date")
+}
+
+#[test]
+fn separators_are_inserted_before_the_next_digit() {
+    let mask = DateInputMask::new("DD/MM/YYYY");
+    assert_eq!(mask.apply("05"), "05");
+    assert_eq!(mask.apply("050"), "05/0");
+    assert_eq!(mask.apply("05032024"), "05/03/2024");
+    assert_eq!(mask.apply("05/03/2024"), "05/03/2024");
+}
+
+#[test]
+fn non_digits_and_extra_digits_are_dropped() {
+    let mask = DateInputMask::new("DD/MM/YYYY");
+    assert_eq!(mask.apply("a0b5-03.2024"), "05/03/2024");
+    assert_eq!(mask.apply("0503202499"), "05/03/2024");
+    assert_eq!(mask.apply("abc"), "");
+}
+
+#[test]
+fn too_large_first_digits_are_padded_with_a_zero() {
+    let mask = DateInputMask::new("DD/MM/YYYY");
+    assert_eq!(mask.apply("5"), "05");
+    assert_eq!(mask.apply("053"), "05/03");
+    assert_eq!(mask.apply("3"), "3");
+    assert_eq!(mask.apply("051"), "05/1");
+    let iso_mask = DateInputMask::new("YYYY-MM-DD");
+    assert_eq!(iso_mask.apply("202435"), "2024-03-05");
+}
+
+#[test]
+fn complete_valid_dates_are_parsed() {
+    let mask = DateInputMask::new("DD/MM/YYYY");
+    assert_eq!(mask.parse("05/03/2024"), Some(ymd(2024, 3, 5)));
+    assert_eq!(
+        DateInputMask::new("MM.DD.YYYY").parse("03.05.2024"),
+        Some(ymd(2024, 3, 5))
+    );
+}
+
+#[test]
+fn incomplete_or_invalid_dates_arent_parsed() {
+    let mask = DateInputMask::new("DD/MM/YYYY");
+    assert_eq!(mask.parse("05/03/202"), None);
+    assert_eq!(mask.parse("31/02/2024"), None);
+    assert_eq!(mask.parse("05/13/2024"), None);
+    assert_eq!(mask.parse("05.03.2024"), None);
+    assert_eq!(mask.parse("0a/03/2024"), None);
+}
+
+#[test]
+fn format_writes_the_date_in_the_pattern() {
+    let mask = DateInputMask::new("DD/MM/YYYY");
+    let formatted = mask.format(&ymd(2024, 3, 5));
+    assert_eq!(formatted, "05/03/2024");
+    assert_eq!(mask.parse(&formatted), Some(ymd(2024, 3, 5)));
+}
@@ -0,0 +1,150 @@
+//! Tests of the formatting of the selection, they run natively without wasm.
+use chrono::NaiveDate;
+use seed_datepicker::{
+    config::{date_constraints::DateConstraints, PickerConfigBuilder},
+    dialog_view_type::DialogViewType,
+    format::{format_for_display, format_title, ValueFormat},
+    options::{PickerOptions, PickerOptionsBuilder},
+    year_display::{CommonEra, YearDisplay},
+};
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("Invalid date")
+}
+
+#[test]
+fn value_format_writes_the_pattern() {
+    let value_format = ValueFormat::from("%d.%m.%Y");
+    assert_eq!(
+        value_format.format(&ymd(2024, 3, 5)),
+        Some("05.03.2024".to_owned())
+    );
+    assert!(value_format.is_valid());
+}
+
+#[test]
+fn invalid_value_format_doesnt_panic() {
+    for pattern in ["%Q.%m.%Y", "%d.%m.%Y %H:%M"] {
+        let value_format = ValueFormat::from(pattern);
+        assert_eq!(value_format.format(&ymd(2024, 3, 5)), None);
+        assert!(!value_format.is_valid());
+    }
+}
+
+#[test]
+fn options_builder_rejects_an_invalid_value_format() {
+    assert!(PickerOptionsBuilder::default()
+        .value_format("%Q.%m.%Y")
+        .build()
+        .is_err());
+    assert!(PickerOptionsBuilder::default()
+        .value_format("%e %b %Y")
+        .build()
+        .is_ok());
+}
+
+#[test]
+fn format_for_display_uses_the_month_title_format() {
+    let config = PickerConfigBuilder::<DateConstraints>::default()
+        .build()
+        .unwrap();
+    assert_eq!(
+        format_for_display(&ymd(2024, 3, 5), &config, &PickerOptions::default()),
+        "5 Mar 2024"
+    );
+}
+
+#[test]
+fn format_for_display_uses_the_year_display() {
+    let config = PickerConfigBuilder::<DateConstraints>::default()
+        .selection_type(DialogViewType::Years)
+        .initial_view_type(DialogViewType::Years)
+        .build()
+        .unwrap();
+    let options = PickerOptionsBuilder::default()
+        .year_display(CommonEra)
+        .build()
+        .unwrap();
+    assert_eq!(
+        format_for_display(&ymd(-43, 3, 15), &config, &options),
+        "44 BCE"
+    );
+}
+
+#[test]
+fn format_for_display_uses_the_title_formatter() {
+    let config = PickerConfigBuilder::<DateConstraints>::default()
+        .selection_type(DialogViewType::Months)
+        .initial_view_type(DialogViewType::Months)
+        .build()
+        .unwrap();
+    let options = PickerOptionsBuilder::default()
+        .title_formatter(|_, date| date.format("%m/%Y").to_string())
+        .build()
+        .unwrap();
+    assert_eq!(
+        format_for_display(&ymd(2024, 3, 5), &config, &options),
+        "03/2024"
+    );
+    assert_eq!(
+        format_title(&DialogViewType::Days, &ymd(2024, 3, 5), &config, &options),
+        "03/2024"
+    );
+}
+
+#[test]
+fn negative_year_groups_are_aligned_like_the_positive_ones() {
+    let config = PickerConfigBuilder::<DateConstraints>::default()
+        .build()
+        .unwrap();
+    let title = |year| {
+        format_title(
+            &DialogViewType::Years,
+            &ymd(year, 1, 1),
+            &config,
+            &PickerOptions::default(),
+        )
+    };
+    assert_eq!(title(-1), "-20 - -1");
+    assert_eq!(title(-20), "-20 - -1");
+    assert_eq!(title(-21), "-40 - -21");
+    assert_eq!(title(0), "0 - 19");
+}
+
+#[test]
+fn common_era_counts_the_year_zero_as_1_bce() {
+    assert_eq!(CommonEra.display_year(0), "1 BCE");
+    assert_eq!(CommonEra.display_year(-43), "44 BCE");
+    assert_eq!(CommonEra.display_year(1), "1");
+}
+
+#[test]
+fn days_title_uses_the_year_display() {
+    let config = PickerConfigBuilder::<DateConstraints>::default()
+        .build()
+        .unwrap();
+    let options = PickerOptionsBuilder::default()
+        .year_display(CommonEra)
+        .build()
+        .unwrap();
+    assert_eq!(
+        format_title(&DialogViewType::Days, &ymd(-43, 3, 15), &config, &options),
+        "Mar 44 BCE"
+    );
+}
+
+#[test]
+fn options_builder_rejects_zero_grid_columns() {
+    assert!(PickerOptionsBuilder::default()
+        .year_grid_columns(0)
+        .build()
+        .is_err());
+    assert!(PickerOptionsBuilder::default()
+        .month_grid_columns(0)
+        .build()
+        .is_err());
+    assert!(PickerOptionsBuilder::default()
+        .month_grid_columns(4)
+        .build()
+        .is_ok());
+}
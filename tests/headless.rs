@@ -0,0 +1,360 @@
+//! Tests of the renderer-agnostic selection state, they run natively without wasm.
+use chrono::NaiveDate;
+use seed_datepicker::{
+    config::{
+        date_constraints::{DateConstraints, DateConstraintsBuilder},
+        PickerConfig, PickerConfigBuilder,
+    },
+    dialog_view_type::DialogViewType,
+    headless::{
+        weekday_labels, SelectionState, ViewChange, ViewMsg, ViewState, SELECTION_HISTORY_LENGTH,
+    },
+    options::{DaysLayout, PickerOptions, PickerOptionsBuilder},
+    range::DateRange,
+};
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("Invalid date")
+}
+
+#[test]
+fn toggling_the_selected_date_clears_it() {
+    let mut state = SelectionState::new(None);
+    assert_eq!(state.toggle(ymd(2024, 3, 5)), Some(ymd(2024, 3, 5)));
+    assert_eq!(state.toggle(ymd(2024, 3, 5)), None);
+    assert_eq!(state.selected_date(), &None);
+}
+
+#[test]
+fn range_is_complete_after_its_second_end() {
+    let mut state = SelectionState::new(None);
+    assert_eq!(state.select_range_end(ymd(2024, 3, 20)), None);
+    assert_eq!(state.range_start(), &Some(ymd(2024, 3, 20)));
+    let range = DateRange::new(ymd(2024, 3, 5), ymd(2024, 3, 20));
+    assert_eq!(state.select_range_end(ymd(2024, 3, 5)), Some(range));
+    assert_eq!(state.range_start(), &None);
+    assert_eq!(state.reported(true), Some(ymd(2024, 3, 20)));
+}
+
+#[test]
+fn staged_date_is_selected_on_confirm() {
+    let mut state = SelectionState::new(Some(ymd(2024, 3, 1)));
+    state.stage(ymd(2024, 3, 5));
+    assert_eq!(state.displayed(), Some(ymd(2024, 3, 5)));
+    assert_eq!(state.selected_date(), &Some(ymd(2024, 3, 1)));
+    assert_eq!(state.confirm(), Some(ymd(2024, 3, 5)));
+    assert_eq!(state.confirm(), None);
+    assert_eq!(state.selected_date(), &Some(ymd(2024, 3, 5)));
+}
+
+#[test]
+fn undo_and_redo_restore_the_selections() {
+    let mut state = SelectionState::new(None);
+    state.select(ymd(2024, 3, 5));
+    state.select(ymd(2024, 3, 6));
+    assert!(state.undo());
+    assert_eq!(state.selected_date(), &Some(ymd(2024, 3, 5)));
+    assert!(state.redo());
+    assert_eq!(state.selected_date(), &Some(ymd(2024, 3, 6)));
+    assert!(!state.can_redo());
+}
+
+#[test]
+fn new_selection_discards_the_redo_history() {
+    let mut state = SelectionState::new(None);
+    state.select(ymd(2024, 3, 5));
+    assert!(state.undo());
+    state.select(ymd(2024, 3, 7));
+    assert!(!state.redo());
+}
+
+#[test]
+fn reselecting_the_same_date_isnt_undoable() {
+    let mut state = SelectionState::new(None);
+    state.select(ymd(2024, 3, 5));
+    state.select(ymd(2024, 3, 5));
+    assert!(state.undo());
+    assert_eq!(state.selected_date(), &None);
+    assert!(!state.can_undo());
+}
+
+#[test]
+fn undo_history_is_limited() {
+    let mut state = SelectionState::new(None);
+    for day in 1..=SELECTION_HISTORY_LENGTH as u32 + 5 {
+        state.select(ymd(2024, 3, day));
+    }
+    let mut undone = 0;
+    while state.undo() {
+        undone += 1;
+    }
+    assert_eq!(undone, SELECTION_HISTORY_LENGTH);
+}
+
+/// config viewing March 2024 with the given view and selection types, limited to the year 2024
+fn view_config(
+    initial_view_type: DialogViewType,
+    selection_type: DialogViewType,
+) -> PickerConfig<DateConstraints> {
+    PickerConfigBuilder::default()
+        .initial_date(ymd(2024, 3, 5))
+        .initial_view_type(initial_view_type)
+        .selection_type(selection_type)
+        .date_constraints(
+            DateConstraintsBuilder::default()
+                .min_date(ymd(2024, 1, 1))
+                .max_date(ymd(2024, 12, 31))
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn navigation_stops_at_the_constraints() {
+    let config = view_config(DialogViewType::Days, DialogViewType::Days);
+    let options = PickerOptions::default();
+    let mut view = ViewState::new(&config, &options);
+    assert_eq!(
+        view.update(ViewMsg::Previous, &config, &options),
+        ViewChange::Navigated
+    );
+    assert_eq!(view.viewed_date(), &ymd(2024, 2, 1));
+    view.update(ViewMsg::Previous, &config, &options);
+    assert!(!view.can_navigate_previous(&config, &options));
+    assert_eq!(
+        view.update(ViewMsg::Previous, &config, &options),
+        ViewChange::Unchanged
+    );
+    assert_eq!(view.viewed_date(), &ymd(2024, 1, 1));
+}
+
+#[test]
+fn navigation_min_limits_the_previous_pages() {
+    let config = view_config(DialogViewType::Days, DialogViewType::Days);
+    let options = PickerOptionsBuilder::default()
+        .navigation_min(ymd(2024, 3, 1))
+        .build()
+        .unwrap();
+    let view = ViewState::new(&config, &options);
+    assert!(!view.can_navigate_previous(&config, &options));
+    assert!(view.can_navigate_next(&config, &options));
+}
+
+#[test]
+fn zoom_is_limited_by_the_selection_type() {
+    let config = view_config(DialogViewType::Months, DialogViewType::Months);
+    let options = PickerOptions::default();
+    let mut view = ViewState::new(&config, &options);
+    assert_eq!(
+        view.update(ViewMsg::ZoomIn, &config, &options),
+        ViewChange::Unchanged
+    );
+    assert_eq!(
+        view.update(ViewMsg::ZoomOut, &config, &options),
+        ViewChange::Zoomed
+    );
+    assert_eq!(view.dialog_view_type(), &DialogViewType::Years);
+    assert_eq!(view.title(&config, &options), "2020 - 2039");
+}
+
+#[test]
+fn month_of_the_selection_type_is_selected() {
+    let config = view_config(DialogViewType::Months, DialogViewType::Months);
+    let options = PickerOptions::default();
+    let mut view = ViewState::new(&config, &options);
+    assert_eq!(
+        view.update(ViewMsg::MonthSelected(7), &config, &options),
+        ViewChange::Selected(ymd(2024, 7, 1))
+    );
+}
+
+#[test]
+fn selected_month_is_displayed_in_the_days_view() {
+    let config = view_config(DialogViewType::Months, DialogViewType::Days);
+    let options = PickerOptions::default();
+    let mut view = ViewState::new(&config, &options);
+    assert_eq!(
+        view.update(ViewMsg::MonthSelected(7), &config, &options),
+        ViewChange::Zoomed
+    );
+    assert_eq!(view.dialog_view_type(), &DialogViewType::Days);
+    assert_eq!(view.viewed_date(), &ymd(2024, 7, 5));
+}
+
+#[test]
+fn days_view_cells_fill_six_weeks() {
+    let config = view_config(DialogViewType::Days, DialogViewType::Days);
+    let options = PickerOptionsBuilder::default()
+        .today_provider(|| ymd(2024, 3, 8))
+        .build()
+        .unwrap();
+    let view = ViewState::new(&config, &options);
+    let cells = view.cells(
+        &config,
+        &options,
+        &SelectionState::new(Some(ymd(2024, 3, 5))),
+    );
+    assert_eq!(cells.len(), 7 * 6);
+    assert_eq!(view.grid_columns(&options), 7);
+    assert_eq!(cells[0].date, ymd(2024, 2, 26));
+    assert!(cells[0].is_other_month);
+    assert!(!cells[0].is_empty);
+    let selected: Vec<NaiveDate> = cells
+        .iter()
+        .filter(|cell| cell.is_selected)
+        .map(|cell| cell.date)
+        .collect();
+    assert_eq!(selected, vec![ymd(2024, 3, 5)]);
+    let today = cells.iter().find(|cell| cell.is_today).unwrap();
+    assert_eq!(today.date, ymd(2024, 3, 8));
+    assert_eq!(today.label, "8");
+    assert_eq!(today.view_msg, None);
+    assert_eq!(
+        weekday_labels(&options),
+        vec!["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"]
+    );
+}
+
+#[test]
+fn months_and_years_view_cells_zoom_in() {
+    let config = view_config(DialogViewType::Months, DialogViewType::Days);
+    let options = PickerOptions::default();
+    let mut view = ViewState::new(&config, &options);
+    let months = view.cells(
+        &config,
+        &options,
+        &SelectionState::new(Some(ymd(2024, 3, 5))),
+    );
+    assert_eq!(months.len(), 12);
+    assert_eq!(months[6].label, "July");
+    assert_eq!(months[6].view_msg, Some(ViewMsg::MonthSelected(7)));
+    assert!(months[2].is_selected);
+
+    view.update(ViewMsg::ZoomOut, &config, &options);
+    let years = view.cells(&config, &options, &SelectionState::default());
+    assert_eq!(years.len(), 20);
+    assert_eq!(years[0].label, "2020");
+    assert!(years[0].is_forbidden);
+    assert!(!years[4].is_forbidden);
+    assert_eq!(years[4].view_msg, Some(ViewMsg::YearSelected(2024)));
+}
+
+#[test]
+fn week_strip_cells_are_all_in_the_viewed_week() {
+    let config = view_config(DialogViewType::Days, DialogViewType::Days);
+    let options = PickerOptionsBuilder::default()
+        .days_layout(DaysLayout::WeekStrip)
+        .build()
+        .unwrap();
+    let mut view = ViewState::new(&config, &options);
+    view.update(ViewMsg::Navigate(ymd(2024, 2, 28)), &config, &options);
+    let cells = view.cells(&config, &options, &SelectionState::default());
+    assert_eq!(cells.len(), 7);
+    assert_eq!(cells[0].date, ymd(2024, 2, 26));
+    assert!(cells
+        .iter()
+        .all(|cell| !cell.is_other_month && !cell.is_empty));
+}
+
+#[test]
+fn days_outside_of_the_range_window_are_forbidden() {
+    let config = view_config(DialogViewType::Days, DialogViewType::Days);
+    let options = PickerOptionsBuilder::default()
+        .range_selection(true)
+        .max_range_length(3)
+        .build()
+        .unwrap();
+    let view = ViewState::new(&config, &options);
+    let mut selection = SelectionState::new(None);
+    selection.select_range_end(ymd(2024, 3, 5));
+    let cells = view.cells(&config, &options, &selection);
+    let is_forbidden = |date| {
+        cells
+            .iter()
+            .find(|cell| cell.date == date)
+            .unwrap()
+            .is_forbidden
+    };
+    assert!(!is_forbidden(ymd(2024, 3, 8)));
+    assert!(is_forbidden(ymd(2024, 3, 9)));
+    assert!(is_forbidden(ymd(2024, 3, 1)));
+}
+
+#[test]
+fn staged_range_is_selected_once_confirmed() {
+    let mut state = SelectionState::new(None);
+    assert_eq!(state.stage_range_end(ymd(2024, 3, 5)), None);
+    let range = DateRange::new(ymd(2024, 3, 5), ymd(2024, 3, 10));
+    assert_eq!(state.stage_range_end(ymd(2024, 3, 10)), Some(range));
+    assert_eq!(state.selected_range(), &None);
+    assert_eq!(state.displayed_range(), Some(range));
+    assert_eq!(state.confirm_range(), Some(range));
+    assert_eq!(state.selected_range(), &Some(range));
+    assert_eq!(state.pending_range(), &None);
+    assert!(state.can_undo());
+}
+
+#[test]
+fn discarded_range_isnt_selected() {
+    let mut state = SelectionState::new(None);
+    state.stage_range_end(ymd(2024, 3, 5));
+    state.stage_range_end(ymd(2024, 3, 10));
+    state.discard_unfinished();
+    assert_eq!(state.confirm_range(), None);
+    assert_eq!(state.selected_range(), &None);
+}
+
+#[test]
+fn range_orders_its_ends() {
+    let range = DateRange::new(ymd(2024, 3, 10), ymd(2024, 3, 5));
+    assert_eq!(range.start(), ymd(2024, 3, 5));
+    assert_eq!(range.end(), ymd(2024, 3, 10));
+    assert_eq!(range.len_nights(), 5);
+}
+
+#[test]
+fn forbidden_ranges_are_dropped() {
+    let mut state = SelectionState::new(None);
+    state.select_range_end(ymd(2024, 3, 5));
+    state.select_range_end(ymd(2024, 3, 10));
+    state.select_range_end(ymd(2024, 3, 20));
+    let config = PickerConfigBuilder::default()
+        .date_constraints(
+            DateConstraintsBuilder::default()
+                .disabled_unique_dates([ymd(2024, 3, 7), ymd(2024, 3, 20)].into())
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    let options = PickerOptionsBuilder::default()
+        .allow_forbidden_in_range(false)
+        .build()
+        .unwrap();
+    assert!(state.drop_forbidden(&config, &options));
+    assert_eq!(state.selected_range(), &None);
+    assert_eq!(state.range_start(), &None);
+}
+
+#[test]
+fn ranges_over_forbidden_days_are_kept_when_allowed() {
+    let mut state = SelectionState::new(None);
+    state.select_range_end(ymd(2024, 3, 5));
+    state.select_range_end(ymd(2024, 3, 10));
+    let config = PickerConfigBuilder::default()
+        .date_constraints(
+            DateConstraintsBuilder::default()
+                .disabled_unique_dates([ymd(2024, 3, 7)].into())
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    assert!(!state.drop_forbidden(&config, &PickerOptions::default()));
+    assert_eq!(
+        state.selected_range(),
+        &Some(DateRange::new(ymd(2024, 3, 5), ymd(2024, 3, 10)))
+    );
+}
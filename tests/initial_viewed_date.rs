@@ -0,0 +1,110 @@
+//! Tests of the month viewed by a newly created picker, run them with `cargo test --features test-utils`.
+#![cfg(feature = "test-utils")]
+
+use std::{collections::HashSet, iter::FromIterator};
+
+use chrono::{Month, NaiveDate};
+use seed_datepicker::{
+    config::{
+        date_constraints::{DateConstraints, DateConstraintsBuilder},
+        PickerConfigBuilder,
+    },
+    options::PickerOptionsBuilder,
+    test_utils::ModelBuilder,
+};
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("Invalid date")
+}
+
+/// viewed date of a picker created on 2024-03-14 with the given constraints and initial date
+fn viewed_date(constraints: DateConstraints, initial_date: Option<NaiveDate>) -> NaiveDate {
+    let mut config = PickerConfigBuilder::default();
+    config.date_constraints(constraints.clone());
+    if let Some(initial_date) = initial_date {
+        config.initial_date(initial_date);
+    }
+    let options = PickerOptionsBuilder::default()
+        .today_provider(|| ymd(2024, 3, 14))
+        .build()
+        .unwrap();
+    *ModelBuilder::new(config.build().unwrap(), constraints)
+        .options(options)
+        .build()
+        .viewed_date()
+}
+
+#[test]
+fn views_today_without_constraints() {
+    assert_eq!(
+        viewed_date(DateConstraints::default(), None),
+        ymd(2024, 3, 14)
+    );
+}
+
+#[test]
+fn views_the_month_of_a_later_min_date() {
+    let constraints = DateConstraintsBuilder::default()
+        .min_date(ymd(2031, 7, 20))
+        .build()
+        .unwrap();
+    assert_eq!(viewed_date(constraints, None), ymd(2031, 7, 1));
+}
+
+#[test]
+fn views_the_month_of_an_earlier_max_date() {
+    let constraints = DateConstraintsBuilder::default()
+        .max_date(ymd(1999, 11, 5))
+        .build()
+        .unwrap();
+    assert_eq!(viewed_date(constraints, None), ymd(1999, 11, 1));
+}
+
+#[test]
+fn views_the_month_of_a_min_date_centuries_later() {
+    let constraints = DateConstraintsBuilder::default()
+        .min_date(ymd(2231, 7, 20))
+        .build()
+        .unwrap();
+    assert_eq!(viewed_date(constraints, None), ymd(2231, 7, 1));
+}
+
+#[test]
+fn views_the_month_of_a_max_date_centuries_earlier() {
+    let constraints = DateConstraintsBuilder::default()
+        .max_date(ymd(1850, 11, 5))
+        .build()
+        .unwrap();
+    assert_eq!(viewed_date(constraints, None), ymd(1850, 11, 1));
+}
+
+#[test]
+fn views_the_next_month_if_the_current_one_is_disabled() {
+    let constraints = DateConstraintsBuilder::default()
+        .disabled_months(HashSet::from_iter(vec![Month::March]))
+        .build()
+        .unwrap();
+    assert_eq!(viewed_date(constraints, None), ymd(2024, 4, 1));
+}
+
+#[test]
+fn skips_disabled_years() {
+    let constraints = DateConstraintsBuilder::default()
+        .min_date(ymd(2024, 1, 1))
+        .disabled_years(HashSet::from_iter(vec![2024, 2025]))
+        .build()
+        .unwrap();
+    assert_eq!(viewed_date(constraints, None), ymd(2026, 1, 1));
+}
+
+#[test]
+fn views_the_month_of_the_initial_date() {
+    let constraints = DateConstraintsBuilder::default()
+        .min_date(ymd(2020, 1, 1))
+        .build()
+        .unwrap();
+    assert_eq!(
+        viewed_date(constraints, Some(ymd(2022, 6, 15))),
+        ymd(2022, 6, 15)
+    );
+}
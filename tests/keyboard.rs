@@ -0,0 +1,25 @@
+//! Tests of the key bindings, they run natively without wasm.
+use seed_datepicker::keyboard::{KeyAction, KeyBindings, KeyCombination};
+
+#[test]
+fn vim_braces_navigate_by_years_with_or_without_shift() {
+    let key_bindings = KeyBindings::vim();
+    assert_eq!(
+        key_bindings.action(&KeyCombination::new("{")),
+        Some(KeyAction::PreviousYear)
+    );
+    assert_eq!(
+        key_bindings.action(&KeyCombination::new("}").shift()),
+        Some(KeyAction::NextYear)
+    );
+}
+
+#[test]
+fn shift_is_not_ignored_for_named_keys() {
+    let mut key_bindings = KeyBindings::empty();
+    key_bindings.bind(KeyCombination::new("PageDown"), KeyAction::NextMonth);
+    assert_eq!(
+        key_bindings.action(&KeyCombination::new("PageDown").shift()),
+        None
+    );
+}
@@ -0,0 +1,72 @@
+//! Tests of the navigation between the pages of the dialog, run them with `cargo test --features test-utils`.
+#![cfg(feature = "test-utils")]
+
+use chrono::NaiveDate;
+use seed_datepicker::{
+    config::{
+        date_constraints::{DateConstraints, DateConstraintsBuilder, DateRejection},
+        PickerConfigBuilder,
+    },
+    dialog_view_type::DialogViewType,
+    test_utils::ModelBuilder,
+    Model,
+};
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("Invalid date")
+}
+
+/// opened dialog viewing the `view_type` page of the `viewed_date` with the given constraints
+fn model(
+    constraints: DateConstraints,
+    view_type: DialogViewType,
+    viewed_date: NaiveDate,
+) -> Model<DateConstraints> {
+    let config = PickerConfigBuilder::default()
+        .date_constraints(constraints.clone())
+        .build()
+        .unwrap();
+    ModelBuilder::new(config, constraints)
+        .dialog_view_type(view_type)
+        .viewed_date(viewed_date)
+        .dialog_opened(true)
+        .build()
+}
+
+#[test]
+fn previous_negative_year_group_is_allowed_only_before_its_first_year() {
+    let min_date = |year| {
+        DateConstraintsBuilder::default()
+            .min_date(ymd(year, 1, 1))
+            .build()
+            .unwrap()
+    };
+    // the group of the year -1 is -20..=-1
+    let model_in_group = |year| model(min_date(year), DialogViewType::Years, ymd(-1, 6, 1));
+    assert!(!model_in_group(-20).can_navigate_previous());
+    assert!(model_in_group(-21).can_navigate_previous());
+}
+
+#[test]
+fn next_year_group_is_allowed_until_the_max_date() {
+    let constraints = DateConstraintsBuilder::default()
+        .max_date(ymd(-1, 12, 31))
+        .build()
+        .unwrap();
+    assert!(!model(constraints.clone(), DialogViewType::Years, ymd(-20, 1, 1)).can_navigate_next());
+    assert!(model(constraints, DialogViewType::Years, ymd(-21, 1, 1)).can_navigate_next());
+}
+
+#[test]
+fn validate_reports_the_rule_of_the_stored_constraints() {
+    let constraints = DateConstraintsBuilder::default()
+        .min_date(ymd(2024, 3, 10))
+        .build()
+        .unwrap();
+    let model = model(constraints, DialogViewType::Days, ymd(2024, 3, 1));
+    assert_eq!(
+        model.validate(ymd(2024, 3, 5)),
+        Err(DateRejection::BeforeMin(ymd(2024, 3, 10)))
+    );
+    assert_eq!(model.validate(ymd(2024, 3, 12)), Ok(()));
+}
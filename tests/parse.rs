@@ -0,0 +1,87 @@
+//! Tests of the parsing of the typed dates, run them with `cargo test --features natural-language`.
+#![cfg(feature = "natural-language")]
+
+use chrono::NaiveDate;
+use seed_datepicker::{
+    options::TwoDigitYear,
+    parse::{parse_date, parse_date_with},
+};
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("Invalid date")
+}
+
+#[test]
+fn two_digit_years_below_the_default_pivot_are_in_the_2000s() {
+    let today = ymd(2024, 3, 14);
+    assert_eq!(parse_date("05.03.24", today), Some(ymd(2024, 3, 5)));
+    assert_eq!(parse_date("5/3/49", today), Some(ymd(2049, 3, 5)));
+}
+
+#[test]
+fn two_digit_years_from_the_default_pivot_are_in_the_1900s() {
+    let today = ymd(2024, 3, 14);
+    assert_eq!(parse_date("05.03.50", today), Some(ymd(1950, 3, 5)));
+    assert_eq!(parse_date("31/12/99", today), Some(ymd(1999, 12, 31)));
+}
+
+#[test]
+fn custom_pivot_moves_the_century_boundary() {
+    let today = ymd(2024, 3, 14);
+    let pivot = TwoDigitYear::Pivot(30);
+    assert_eq!(
+        parse_date_with("05.03.29", today, pivot),
+        Some(ymd(2029, 3, 5))
+    );
+    assert_eq!(
+        parse_date_with("05.03.30", today, pivot),
+        Some(ymd(1930, 3, 5))
+    );
+}
+
+#[test]
+fn closest_to_today_picks_the_nearest_century() {
+    let closest = TwoDigitYear::ClosestToToday;
+    assert_eq!(closest.full_year(30, ymd(2024, 3, 14)), 2030);
+    assert_eq!(closest.full_year(80, ymd(2024, 3, 14)), 1980);
+    assert_eq!(closest.full_year(5, ymd(2097, 1, 1)), 2105);
+    assert_eq!(
+        parse_date_with("05.03.80", ymd(2024, 3, 14), closest),
+        Some(ymd(1980, 3, 5))
+    );
+}
+
+#[test]
+fn four_digit_years_are_not_affected() {
+    let today = ymd(2024, 3, 14);
+    assert_eq!(parse_date("05.03.1924", today), Some(ymd(1924, 3, 5)));
+    assert_eq!(parse_date("2024-03-05", today), Some(ymd(2024, 3, 5)));
+}
+
+#[test]
+fn invalid_dates_with_two_digit_years_are_rejected() {
+    assert_eq!(parse_date("30.02.24", ymd(2024, 3, 14)), None);
+}
+
+#[test]
+fn iso_week_dates_are_parsed() {
+    let today = ymd(2024, 3, 14);
+    assert_eq!(parse_date("2024-W15-3", today), Some(ymd(2024, 4, 10)));
+    assert_eq!(parse_date(" 2020-W53-5 ", today), Some(ymd(2021, 1, 1)));
+    assert_eq!(parse_date("2024-W54-1", today), None);
+}
+
+#[test]
+fn ordinal_dates_are_parsed() {
+    let today = ymd(2024, 3, 14);
+    assert_eq!(parse_date("2024-105", today), Some(ymd(2024, 4, 14)));
+    assert_eq!(parse_date("2024-366", today), Some(ymd(2024, 12, 31)));
+    assert_eq!(parse_date("2023-366", today), None);
+}
+
+#[test]
+fn month_names_are_case_insensitive() {
+    let today = ymd(2024, 3, 14);
+    assert_eq!(parse_date("5 Mar 2024", today), Some(ymd(2024, 3, 5)));
+    assert_eq!(parse_date("5 MAR 2024", today), Some(ymd(2024, 3, 5)));
+}
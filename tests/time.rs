@@ -0,0 +1,21 @@
+//! Tests of the `PickerDate` conversions of the `time` crate, they run natively without wasm.
+#![cfg(feature = "time")]
+use chrono::NaiveDate;
+use seed_datepicker::{date::PickerDate, Msg};
+
+#[test]
+fn time_date_round_trips() {
+    let date = time::Date::from_calendar_date(2024, time::Month::March, 5).unwrap();
+    let naive_date = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+    assert_eq!(date.to_naive_date(), Some(naive_date));
+    assert_eq!(time::Date::from_naive_date(naive_date), Some(date));
+    assert_eq!(
+        Msg::date_selected(&date),
+        Some(Msg::DateSelected(naive_date))
+    );
+}
+
+#[test]
+fn dates_beyond_the_time_range_are_none() {
+    assert_eq!(time::Date::from_naive_date(NaiveDate::MAX), None);
+}
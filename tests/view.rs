@@ -0,0 +1,402 @@
+//! Browser tests of the view layer, run them with `wasm-pack test --headless --firefox`.
+#![cfg(target_arch = "wasm32")]
+
+use std::cell::RefCell;
+
+use chrono::NaiveDate;
+use seed::{prelude::*, *};
+use seed_datepicker::{
+    config::{
+        date_constraints::{DateConstraints, DateConstraintsBuilder},
+        PickerConfig, PickerConfigBuilder,
+    },
+    dialog_view_type::DialogViewType,
+    format::format_title,
+    options::{PickerOptions, PickerOptionsBuilder},
+    year_display::CommonEra,
+};
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::*;
+use web_sys::{Element, HtmlElement};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+thread_local! {
+    /// selected dates received through the `on_change` message of the currently tested app
+    static CHANGES: RefCell<Vec<Option<NaiveDate>>> = RefCell::new(Vec::new());
+}
+
+// ------ test app ------
+
+struct TestModel {
+    date_picker: seed_datepicker::Model<DateConstraints>,
+}
+
+enum TestMsg {
+    DatePickerMsg(seed_datepicker::Msg),
+    DateSelected,
+}
+
+fn update(msg: TestMsg, model: &mut TestModel, orders: &mut impl Orders<TestMsg>) {
+    match msg {
+        TestMsg::DatePickerMsg(picker_msg) => seed_datepicker::update(
+            picker_msg,
+            &mut model.date_picker,
+            orders,
+            |_| TestMsg::DateSelected,
+            TestMsg::DatePickerMsg,
+        ),
+        TestMsg::DateSelected => {
+            let selected_date = *model.date_picker.selected_date();
+            CHANGES.with(|changes| changes.borrow_mut().push(selected_date));
+        }
+    }
+}
+
+fn view(model: &TestModel) -> Node<TestMsg> {
+    seed_datepicker::view(&model.date_picker, TestMsg::DatePickerMsg)
+}
+
+// ------ helpers ------
+
+/// mounts a new app with the datepicker into a fresh element and waits for its first render
+async fn mount(config: PickerConfig<DateConstraints>) -> Element {
+    mount_with_options(config, DateConstraints::default(), PickerOptions::default()).await
+}
+
+/// `mount` of the `config` built with the `date_constraints`, with the given `PickerOptions`
+async fn mount_with_options(
+    config: PickerConfig<DateConstraints>,
+    date_constraints: DateConstraints,
+    options: PickerOptions,
+) -> Element {
+    CHANGES.with(|changes| changes.borrow_mut().clear());
+    let root = document().create_element("div").unwrap();
+    document().body().unwrap().append_child(&root).unwrap();
+    App::start(
+        root.clone(),
+        move |url, orders| TestModel {
+            date_picker: seed_datepicker::init_with_options(
+                url,
+                orders,
+                config,
+                date_constraints,
+                options,
+                TestMsg::DatePickerMsg,
+            ),
+        },
+        update,
+        view,
+    );
+    next_frame().await;
+    root
+}
+
+/// waits until seed renders the changes scheduled before the call
+async fn next_frame() {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        window().request_animation_frame(&resolve).unwrap();
+    });
+    JsFuture::from(promise).await.unwrap();
+}
+
+fn opened_config(initial_date: NaiveDate) -> PickerConfigBuilder<DateConstraints> {
+    let mut builder = PickerConfigBuilder::default();
+    builder.initial_date(initial_date).initially_opened(true);
+    builder
+}
+
+fn find(root: &Element, selector: &str) -> Option<Element> {
+    root.query_selector(selector).unwrap()
+}
+
+/// finds the cell of the dialog body with the given text, other month days are skipped
+fn cell(root: &Element, text: &str) -> Element {
+    let cells = root
+        .query_selector_all(".datepicker-root .body span:not(.other-month)")
+        .unwrap();
+    (0..cells.length())
+        .filter_map(|index| cells.item(index))
+        .map(|node| node.unchecked_into::<Element>())
+        .find(|element| element.text_content().as_deref() == Some(text))
+        .unwrap_or_else(|| panic!("no cell with text {}", text))
+}
+
+fn click(element: &Element) {
+    element.unchecked_ref::<HtmlElement>().click();
+}
+
+async fn click_selector(root: &Element, selector: &str) {
+    click(&find(root, selector).unwrap_or_else(|| panic!("no element {}", selector)));
+    next_frame().await;
+}
+
+async fn click_cell(root: &Element, text: &str) {
+    click(&cell(root, text));
+    next_frame().await;
+}
+
+async fn double_click_title(root: &Element) {
+    find(root, ".header .title")
+        .unwrap()
+        .dispatch_event(&web_sys::Event::new("dblclick").unwrap())
+        .unwrap();
+    next_frame().await;
+    // the double-click is handled with a message sent to the next update
+    next_frame().await;
+}
+
+fn title(root: &Element) -> String {
+    find(root, ".datepicker-root .header .title")
+        .and_then(|title| title.text_content())
+        .unwrap_or_default()
+}
+
+fn changes() -> Vec<Option<NaiveDate>> {
+    CHANGES.with(|changes| changes.borrow().clone())
+}
+
+// ------ tests ------
+
+#[wasm_bindgen_test]
+async fn renders_the_selected_day() {
+    let root = mount(
+        opened_config(NaiveDate::from_ymd(2020, 12, 15))
+            .build()
+            .unwrap(),
+    )
+    .await;
+
+    assert_eq!(title(&root), "Dec 2020");
+    let selected = cell(&root, "15");
+    assert!(selected.class_list().contains("selected"));
+    assert!(selected.class_list().contains("selectable"));
+    assert_eq!(
+        selected.get_attribute("aria-selected").as_deref(),
+        Some("true")
+    );
+    assert!(!cell(&root, "16").class_list().contains("selected"));
+}
+
+#[wasm_bindgen_test]
+async fn clicking_a_day_selects_it_and_closes_the_dialog() {
+    let root = mount(
+        opened_config(NaiveDate::from_ymd(2020, 12, 15))
+            .build()
+            .unwrap(),
+    )
+    .await;
+
+    click_cell(&root, "20").await;
+
+    assert_eq!(changes(), vec![Some(NaiveDate::from_ymd(2020, 12, 20))]);
+    assert!(find(&root, ".datepicker-root").is_none());
+}
+
+#[wasm_bindgen_test]
+async fn clicking_the_title_zooms_out() {
+    let root = mount(
+        opened_config(NaiveDate::from_ymd(2020, 12, 15))
+            .build()
+            .unwrap(),
+    )
+    .await;
+
+    click_selector(&root, ".header .title").await;
+    assert_eq!(title(&root), "2020");
+    assert!(cell(&root, "December").class_list().contains("selected"));
+
+    click_selector(&root, ".header .title").await;
+    assert!(title(&root).contains("2020"));
+    assert!(cell(&root, "2020").class_list().contains("selected"));
+    assert!(changes().is_empty());
+}
+
+#[wasm_bindgen_test]
+async fn clicking_a_year_and_a_month_zooms_in() {
+    let root = mount(
+        opened_config(NaiveDate::from_ymd(2020, 12, 15))
+            .initial_view_type(DialogViewType::Years)
+            .build()
+            .unwrap(),
+    )
+    .await;
+
+    click_cell(&root, "2021").await;
+    assert_eq!(title(&root), "2021");
+
+    click_cell(&root, "March").await;
+    assert_eq!(title(&root), "Mar 2021");
+    assert!(changes().is_empty());
+}
+
+#[wasm_bindgen_test]
+async fn previous_and_next_buttons_change_the_viewed_month() {
+    let root = mount(
+        opened_config(NaiveDate::from_ymd(2020, 12, 15))
+            .build()
+            .unwrap(),
+    )
+    .await;
+
+    click_selector(&root, ".header .next").await;
+    assert_eq!(title(&root), "Jan 2021");
+
+    click_selector(&root, ".header .previous").await;
+    click_selector(&root, ".header .previous").await;
+    assert_eq!(title(&root), "Nov 2020");
+}
+
+#[wasm_bindgen_test]
+async fn close_button_closes_the_dialog() {
+    let root = mount(
+        opened_config(NaiveDate::from_ymd(2020, 12, 15))
+            .build()
+            .unwrap(),
+    )
+    .await;
+
+    click_selector(&root, ".header .close").await;
+    assert!(find(&root, ".datepicker-root").is_none());
+    assert!(changes().is_empty());
+}
+
+#[wasm_bindgen_test]
+async fn forbidden_days_are_not_clickable() {
+    let constraints = DateConstraintsBuilder::default()
+        .min_date(NaiveDate::from_ymd(2020, 12, 10))
+        .build()
+        .unwrap();
+    let root = mount_with_options(
+        opened_config(NaiveDate::from_ymd(2020, 12, 15))
+            .date_constraints(constraints.clone())
+            .build()
+            .unwrap(),
+        constraints,
+        PickerOptions::default(),
+    )
+    .await;
+
+    let forbidden = cell(&root, "5");
+    assert!(forbidden.class_list().contains("unavailable"));
+    click_cell(&root, "5").await;
+
+    assert!(changes().is_empty());
+    assert!(find(&root, ".datepicker-root").is_some());
+}
+
+#[wasm_bindgen_test]
+async fn format_title_matches_the_rendered_title() {
+    let viewed_date = NaiveDate::from_ymd_opt(2020, 12, 15).unwrap();
+    let cases = [
+        (DialogViewType::Days, PickerOptions::default()),
+        (
+            DialogViewType::Months,
+            PickerOptionsBuilder::default()
+                .fiscal_year_start(chrono::Month::April)
+                .build()
+                .unwrap(),
+        ),
+        (
+            DialogViewType::Years,
+            PickerOptionsBuilder::default()
+                .year_display(CommonEra)
+                .build()
+                .unwrap(),
+        ),
+        (
+            DialogViewType::Days,
+            PickerOptionsBuilder::default()
+                .title_formatter(|view_type, date| format!("{:?} {}", view_type, date))
+                .build()
+                .unwrap(),
+        ),
+    ];
+    for (view_type, options) in cases.iter() {
+        let config = opened_config(viewed_date)
+            .initial_view_type(*view_type)
+            .build()
+            .unwrap();
+        let expected = format_title(view_type, &viewed_date, &config, options);
+        let root = mount_with_options(config, DateConstraints::default(), options.clone()).await;
+        assert_eq!(title(&root), expected);
+    }
+}
+
+#[wasm_bindgen_test]
+async fn auto_select_first_allowed_reaches_a_distant_min_date() {
+    let min_date = NaiveDate::from_ymd_opt(2231, 7, 20).unwrap();
+    let constraints = DateConstraintsBuilder::default()
+        .min_date(min_date)
+        .build()
+        .unwrap();
+    let config = PickerConfigBuilder::default()
+        .date_constraints(constraints.clone())
+        .build()
+        .unwrap();
+    let options = PickerOptionsBuilder::default()
+        .auto_select_first_allowed(true)
+        .today_provider(|| NaiveDate::from_ymd_opt(2024, 3, 14).unwrap())
+        .build()
+        .unwrap();
+    mount_with_options(config, constraints, options).await;
+    CHANGES.with(|changes| assert_eq!(*changes.borrow(), vec![Some(min_date)]));
+}
+
+#[wasm_bindgen_test]
+async fn double_clicking_the_title_cycles_back_to_the_days() {
+    let root = mount_with_options(
+        opened_config(NaiveDate::from_ymd_opt(2020, 12, 15).unwrap())
+            .build()
+            .unwrap(),
+        DateConstraints::default(),
+        PickerOptionsBuilder::default()
+            .zoom_on_double_click(true)
+            .build()
+            .unwrap(),
+    )
+    .await;
+    double_click_title(&root).await;
+    assert_eq!(title(&root), "2020");
+    double_click_title(&root).await;
+    assert_eq!(title(&root), "2020 - 2039");
+    double_click_title(&root).await;
+    assert_eq!(title(&root), "Dec 2020");
+}
+
+#[wasm_bindgen_test]
+async fn portal_renders_the_dialog_into_the_body() {
+    let root = mount_with_options(
+        opened_config(NaiveDate::from_ymd_opt(2020, 12, 15).unwrap())
+            .build()
+            .unwrap(),
+        DateConstraints::default(),
+        PickerOptionsBuilder::default()
+            .portal(true)
+            .build()
+            .unwrap(),
+    )
+    .await;
+    next_frame().await;
+    assert!(find(&root, ".datepicker-root").is_none());
+    let portal = document().body().unwrap().last_element_child().unwrap();
+    assert_eq!(portal.class_name(), "datepicker-portal");
+    assert_eq!(title(&portal), "Dec 2020");
+}
+
+#[wasm_bindgen_test]
+async fn live_region_announces_the_formatted_title() {
+    let root = mount_with_options(
+        opened_config(NaiveDate::from_ymd_opt(2020, 12, 15).unwrap())
+            .build()
+            .unwrap(),
+        DateConstraints::default(),
+        PickerOptionsBuilder::default()
+            .live_region_formatter(|title| format!("Zobrazeno: {}", title))
+            .build()
+            .unwrap(),
+    )
+    .await;
+    let live_region = find(&root, ".live-region").unwrap();
+    assert_eq!(live_region.text_content().unwrap(), "Zobrazeno: Dec 2020");
+}